@@ -0,0 +1,261 @@
+//! `fusio-manifest-bench run <spec.json|spec.toml> [...] [--sweep <name>]`
+//! `fusio-manifest-bench compare <before-sweep> <after-sweep> [--write-tps-drop-ratio <f>] [--write-p99-increase-ms <f>] [--failure-rate-increase <f>]`
+//!
+//! `run` loads one or more declarative `WorkloadFile` specs and drives each against real S3
+//! via `WorkloadDriver`, writing a CSV of results next to the spec file and persisting every
+//! `(WorkloadConfig, MetricsSummary)` into `perf_results.sqlite3` under `--sweep` (default:
+//! the spec file's stem). This is the batch-mode counterpart to the hand-written `#[ignore]`d
+//! scenarios (`test_baseline`, `test_overlap_sweep`, `test_comprehensive_sweep`,
+//! `test_chaos_sweep`) in `tests/performance_test.rs`: a new scenario can be added as a config
+//! file instead of a recompiled test.
+//!
+//! `compare` diffs two previously recorded `--sweep` names against each other
+//! (`ResultsStore::detect_regressions`) and exits non-zero if any config regressed beyond the
+//! given thresholds, so it can gate a PR instead of requiring someone to eyeball a CSV.
+//!
+//! `perf_test` is test-support code that lives under `tests/` rather than `src/`, so it's
+//! pulled in here by path, the same way `tests/performance_test.rs` pulls it in as a sibling
+//! module.
+#[path = "../../tests/perf_test/mod.rs"]
+mod perf_test;
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use fusio::executor::tokio::TokioExecutor;
+use fusio_manifest::s3::S3Manifest;
+use perf_test::{
+    backend::S3Backend,
+    chaos::{ChaosController, ChaosScenario},
+    metrics::MetricsSummary,
+    results_store::{RegressionThresholds, ResultsStore},
+    s3_setup::{
+        create_real_s3_manifest_with_endpoint_override, create_real_s3_manifest_with_prefix,
+        s3_upstream_addr,
+    },
+    spec::{ExpandedWorkload, WorkloadFile},
+    utils::{create_config_label, create_test_prefix, WorkloadConfig},
+    visualization::export_results_csv,
+    workload::WorkloadDriver,
+};
+
+const RESULTS_STORE_PATH: &str = "perf_results.sqlite3";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+    let command = args.next();
+
+    match command.as_deref() {
+        Some("run") => {
+            let mut spec_paths = Vec::new();
+            let mut sweep: Option<String> = None;
+            let mut rest = args;
+            while let Some(arg) = rest.next() {
+                if arg == "--sweep" {
+                    sweep = Some(rest.next().ok_or("--sweep requires a value")?);
+                } else {
+                    spec_paths.push(PathBuf::from(arg));
+                }
+            }
+
+            if spec_paths.is_empty() {
+                eprintln!("usage: fusio-manifest-bench run <spec.json|spec.toml> [...] [--sweep <name>]");
+                std::process::exit(1);
+            }
+
+            for spec_path in &spec_paths {
+                run_spec(spec_path, sweep.as_deref()).await?;
+            }
+        }
+        Some("compare") => {
+            let before = args.next().ok_or("compare requires <before-sweep> <after-sweep>")?;
+            let after = args.next().ok_or("compare requires <before-sweep> <after-sweep>")?;
+            let thresholds = parse_thresholds(args)?;
+
+            if !compare_sweeps(&before, &after, &thresholds)? {
+                std::process::exit(1);
+            }
+        }
+        _ => {
+            eprintln!("usage:");
+            eprintln!("  fusio-manifest-bench run <spec.json|spec.toml> [...] [--sweep <name>]");
+            eprintln!("  fusio-manifest-bench compare <before-sweep> <after-sweep> [--write-tps-drop-ratio <f>] [--write-p99-increase-ms <f>] [--failure-rate-increase <f>]");
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_thresholds(
+    mut args: impl Iterator<Item = String>,
+) -> Result<RegressionThresholds, Box<dyn std::error::Error>> {
+    let mut thresholds = RegressionThresholds::default();
+    while let Some(flag) = args.next() {
+        let value: f64 = args
+            .next()
+            .ok_or_else(|| format!("{flag} requires a value"))?
+            .parse()?;
+        match flag.as_str() {
+            "--write-tps-drop-ratio" => thresholds.write_tps_drop_ratio = value,
+            "--write-p99-increase-ms" => thresholds.write_p99_ms_increase = value,
+            "--failure-rate-increase" => thresholds.precondition_failure_rate_increase = value,
+            other => return Err(format!("unknown flag: {other}").into()),
+        }
+    }
+    Ok(thresholds)
+}
+
+/// Returns `true` if no config regressed beyond `thresholds` between the two sweeps.
+fn compare_sweeps(
+    before: &str,
+    after: &str,
+    thresholds: &RegressionThresholds,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let store = ResultsStore::open(RESULTS_STORE_PATH)?;
+    let regressions = store.detect_regressions(before, after, thresholds)?;
+
+    if regressions.is_empty() {
+        println!("✅ no regressions between '{before}' and '{after}'");
+        return Ok(true);
+    }
+
+    println!("❌ {} config(s) regressed between '{before}' and '{after}':", regressions.len());
+    for r in &regressions {
+        println!(
+            "  {}: write_tps {:+.2}, write_p99_ms {:+.2}, failure_rate {:+.4}",
+            r.config_label, r.write_tps_delta, r.write_p99_ms_delta, r.failure_rate_delta
+        );
+    }
+    Ok(false)
+}
+
+async fn run_spec(spec_path: &Path, sweep: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
+    let file = WorkloadFile::load(spec_path)?;
+    let spec_name = spec_path.file_stem().and_then(|s| s.to_str()).unwrap_or("workload");
+    let sweep_prefix = sweep.unwrap_or(spec_name);
+
+    println!("\n=== Running spec: {} ===", spec_path.display());
+
+    let results = match file.expand() {
+        ExpandedWorkload::Single(config) => vec![run_one(spec_name, None, config).await?],
+        ExpandedWorkload::Sweep(configs) => {
+            let mut results = Vec::with_capacity(configs.len());
+            for config in configs {
+                let label = create_config_label(&config);
+                results.push(run_one(spec_name, Some(label), config).await?);
+            }
+            results
+        }
+        ExpandedWorkload::Chaos(runs) => {
+            let upstream_addr = s3_upstream_addr()?;
+            let mut results = Vec::with_capacity(runs.len());
+            for (scenario, config) in runs {
+                results.push(run_chaos_one(spec_name, &upstream_addr, scenario, config).await?);
+            }
+            results
+        }
+    };
+
+    let csv_path = spec_path.with_extension("csv");
+    export_results_csv(
+        csv_path.to_str().ok_or("spec path is not valid UTF-8")?,
+        &results,
+    )?;
+
+    let store = ResultsStore::open(RESULTS_STORE_PATH)?;
+    for (config, summary) in &results {
+        store.record(sweep_prefix, None, config, summary)?;
+    }
+
+    Ok(())
+}
+
+async fn run_one(
+    spec_name: &str,
+    label: Option<String>,
+    config: WorkloadConfig,
+) -> Result<(WorkloadConfig, MetricsSummary), Box<dyn std::error::Error>> {
+    let test_name = match &label {
+        Some(label) => format!("{}-{}", spec_name, label),
+        None => spec_name.to_string(),
+    };
+
+    let prefix = create_test_prefix(&test_name);
+    let manifest = Arc::new(create_real_s3_manifest_with_prefix(&prefix)?);
+    let backend = Arc::new(S3Backend::new(manifest.clone()));
+
+    let driver = WorkloadDriver::new(config.clone(), backend);
+    println!("--- {} ---", test_name);
+    let summary = driver.run().await;
+    summary.print_report();
+
+    verify_serializable_isolation(&manifest).await?;
+
+    Ok((config, summary))
+}
+
+async fn run_chaos_one(
+    spec_name: &str,
+    upstream_addr: &str,
+    scenario: ChaosScenario,
+    config: WorkloadConfig,
+) -> Result<(WorkloadConfig, MetricsSummary), Box<dyn std::error::Error>> {
+    let scenario_label = scenario.label();
+    let mut chaos_controller = ChaosController::new(scenario);
+
+    // Proxy-backed scenarios (PacketLoss/BandwidthCap/ServerErrors) need the proxy running
+    // before the manifest is built, so its local URL can override the endpoint.
+    let proxy_url = chaos_controller.start_proxy(upstream_addr).await;
+    chaos_controller.start();
+
+    let test_name = format!("{}-chaos-{}", spec_name, scenario_label);
+    let prefix = create_test_prefix(&test_name);
+    let manifest = Arc::new(create_real_s3_manifest_with_endpoint_override(&prefix, proxy_url)?);
+    let backend = Arc::new(S3Backend::new(manifest.clone()));
+
+    let driver = WorkloadDriver::new(config.clone(), backend);
+    println!("--- {} ---", test_name);
+    let summary = driver.run().await;
+
+    chaos_controller.stop().await;
+    summary.print_report();
+
+    verify_serializable_isolation(&manifest).await?;
+
+    Ok((config, summary))
+}
+
+/// Slimmed-down version of `performance_test::verify_serializable_isolation`: checks the
+/// final snapshot for duplicate keys, without that file's narrated step-by-step output.
+async fn verify_serializable_isolation(
+    manifest: &S3Manifest<String, String, TokioExecutor>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use std::collections::HashSet;
+
+    let snapshot = manifest.snapshot().await?;
+
+    let reader = manifest.session_read().await?;
+    let scan_result = reader.scan().await;
+    reader.end().await?;
+    let all_entries = scan_result?;
+
+    let mut seen_keys: HashSet<String> = HashSet::new();
+    for (key, _value) in &all_entries {
+        if !seen_keys.insert(key.clone()) {
+            return Err(format!("duplicate key found in final state: {}", key).into());
+        }
+    }
+
+    println!(
+        "✅ isolation check passed (txn_id={}, {} entries)",
+        snapshot.txn_id.0,
+        all_entries.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use fusio::executor::tokio::TokioExecutor;
+use fusio_manifest::{s3::S3Manifest, types::Error};
+
+/// A staged write session: puts/deletes accumulate locally and are only applied against
+/// the backend on `commit`, so backends can decide atomically whether the whole batch is
+/// accepted.
+#[async_trait]
+pub trait WriteSession: Send {
+    fn put(&mut self, key: String, value: String);
+    fn delete(&mut self, key: String);
+    async fn commit(self: Box<Self>) -> Result<(), Error>;
+}
+
+/// A read session pinned to one consistent snapshot of the manifest.
+#[async_trait]
+pub trait ReadSession: Send {
+    fn snapshot_txn_id(&self) -> u64;
+    fn get(&self, key: &str) -> Option<String>;
+    fn scan(&self) -> Vec<(String, String)>;
+}
+
+/// Abstracts `S3Manifest`'s session/commit surface so `MockClient`/`WorkloadDriver` can run
+/// against either real S3 or a deterministic in-memory backend for local/CI benchmarking.
+#[async_trait]
+pub trait ManifestBackend: Send + Sync {
+    async fn session_write(&self) -> Result<Box<dyn WriteSession>, Error>;
+    async fn session_read(&self) -> Result<Box<dyn ReadSession>, Error>;
+}
+
+enum PendingOp {
+    Put(String, String),
+    Delete(String),
+}
+
+/// `ManifestBackend` adapter over the real `S3Manifest`. The underlying S3 session is opened
+/// as soon as the `WriteSession` is created (not deferred to `commit`), so its conflict window
+/// matches `InMemoryManifest`'s `base_txn_id` capture: staged `put`/`delete` calls only buffer
+/// locally and are replayed against that already-open session at commit time.
+pub struct S3Backend {
+    manifest: Arc<S3Manifest<String, String, TokioExecutor>>,
+}
+
+impl S3Backend {
+    pub fn new(manifest: Arc<S3Manifest<String, String, TokioExecutor>>) -> Self {
+        Self { manifest }
+    }
+}
+
+struct S3WriteSession {
+    session: fusio_manifest::WriteSession<String, String, TokioExecutor>,
+    ops: Vec<PendingOp>,
+}
+
+#[async_trait]
+impl WriteSession for S3WriteSession {
+    fn put(&mut self, key: String, value: String) {
+        self.ops.push(PendingOp::Put(key, value));
+    }
+
+    fn delete(&mut self, key: String) {
+        self.ops.push(PendingOp::Delete(key));
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), Error> {
+        let S3WriteSession { mut session, ops } = *self;
+        for op in ops {
+            match op {
+                PendingOp::Put(key, value) => session.put(key, value),
+                PendingOp::Delete(key) => session.delete(key),
+            }
+        }
+        session.commit().await
+    }
+}
+
+struct S3ReadSession {
+    snapshot_txn_id: u64,
+    entries: HashMap<String, String>,
+}
+
+#[async_trait]
+impl ReadSession for S3ReadSession {
+    fn snapshot_txn_id(&self) -> u64 {
+        self.snapshot_txn_id
+    }
+
+    fn get(&self, key: &str) -> Option<String> {
+        self.entries.get(key).cloned()
+    }
+
+    fn scan(&self) -> Vec<(String, String)> {
+        self.entries.iter().map(|(k, v)| (k.clone(), v.clone())).collect()
+    }
+}
+
+#[async_trait]
+impl ManifestBackend for S3Backend {
+    async fn session_write(&self) -> Result<Box<dyn WriteSession>, Error> {
+        let session = self.manifest.session_write().await?;
+        Ok(Box::new(S3WriteSession {
+            session,
+            ops: Vec::new(),
+        }))
+    }
+
+    async fn session_read(&self) -> Result<Box<dyn ReadSession>, Error> {
+        let session = self.manifest.session_read().await?;
+        let snapshot_txn_id = session.snapshot().txn_id.0;
+        let entries = session.scan().await?.into_iter().collect();
+        session.end().await?;
+
+        Ok(Box::new(S3ReadSession {
+            snapshot_txn_id,
+            entries,
+        }))
+    }
+}
+
+struct InMemoryState {
+    entries: HashMap<String, String>,
+    txn_id: u64,
+}
+
+/// An in-process `ManifestBackend` that stores the current key/value map plus a
+/// monotonically increasing txn id, enforcing optimistic-concurrency conflicts the same
+/// way a CAS-backed manifest would: a commit succeeds only if the manifest's txn id hasn't
+/// advanced since the write session was opened.
+pub struct InMemoryManifest {
+    state: Arc<Mutex<InMemoryState>>,
+}
+
+impl InMemoryManifest {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(InMemoryState {
+                entries: HashMap::new(),
+                txn_id: 0,
+            })),
+        }
+    }
+}
+
+impl Default for InMemoryManifest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct InMemoryWriteSession {
+    state: Arc<Mutex<InMemoryState>>,
+    base_txn_id: u64,
+    ops: Vec<PendingOp>,
+}
+
+#[async_trait]
+impl WriteSession for InMemoryWriteSession {
+    fn put(&mut self, key: String, value: String) {
+        self.ops.push(PendingOp::Put(key, value));
+    }
+
+    fn delete(&mut self, key: String) {
+        self.ops.push(PendingOp::Delete(key));
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), Error> {
+        let mut state = self.state.lock().unwrap();
+        if state.txn_id != self.base_txn_id {
+            return Err(Error::PreconditionFailed);
+        }
+
+        for op in self.ops {
+            match op {
+                PendingOp::Put(key, value) => {
+                    state.entries.insert(key, value);
+                }
+                PendingOp::Delete(key) => {
+                    state.entries.remove(&key);
+                }
+            }
+        }
+        state.txn_id += 1;
+        Ok(())
+    }
+}
+
+struct InMemoryReadSession {
+    snapshot_txn_id: u64,
+    entries: HashMap<String, String>,
+}
+
+#[async_trait]
+impl ReadSession for InMemoryReadSession {
+    fn snapshot_txn_id(&self) -> u64 {
+        self.snapshot_txn_id
+    }
+
+    fn get(&self, key: &str) -> Option<String> {
+        self.entries.get(key).cloned()
+    }
+
+    fn scan(&self) -> Vec<(String, String)> {
+        self.entries.iter().map(|(k, v)| (k.clone(), v.clone())).collect()
+    }
+}
+
+#[async_trait]
+impl ManifestBackend for InMemoryManifest {
+    async fn session_write(&self) -> Result<Box<dyn WriteSession>, Error> {
+        let base_txn_id = self.state.lock().unwrap().txn_id;
+        Ok(Box::new(InMemoryWriteSession {
+            state: self.state.clone(),
+            base_txn_id,
+            ops: Vec::new(),
+        }))
+    }
+
+    async fn session_read(&self) -> Result<Box<dyn ReadSession>, Error> {
+        let state = self.state.lock().unwrap();
+        Ok(Box::new(InMemoryReadSession {
+            snapshot_txn_id: state.txn_id,
+            entries: state.entries.clone(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_write_then_read() {
+        let manifest = Arc::new(InMemoryManifest::new());
+
+        let mut session = manifest.session_write().await.unwrap();
+        session.put("key_000001".to_string(), "hello".to_string());
+        session.commit().await.unwrap();
+
+        let reader = manifest.session_read().await.unwrap();
+        assert_eq!(reader.get("key_000001"), Some("hello".to_string()));
+        assert_eq!(reader.snapshot_txn_id(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_detects_conflicting_commit() {
+        let manifest = Arc::new(InMemoryManifest::new());
+
+        let mut session_a = manifest.session_write().await.unwrap();
+        let mut session_b = manifest.session_write().await.unwrap();
+
+        session_a.put("key".to_string(), "a".to_string());
+        session_a.commit().await.unwrap();
+
+        session_b.put("key".to_string(), "b".to_string());
+        let result = session_b.commit().await;
+
+        assert!(matches!(result, Err(Error::PreconditionFailed)));
+    }
+}
@@ -0,0 +1,117 @@
+//! Invariant check for `WorkloadMode::Banking`. `verify_serializable_isolation`'s
+//! duplicate-key/monotonic-`txn_id` checks can't catch a lost-update or write-skew anomaly,
+//! since the original write transactions only blind-write independent keys. Banking
+//! transactions instead transfer between two accounts, so the manifest's single global
+//! optimistic CAS (see `crate::perf_test::consistency`) being violated would show up as the
+//! final balance sum no longer matching the known total -- a far stronger correctness signal
+//! than those monotonicity checks.
+
+use crate::perf_test::utils::banking_account_key;
+
+#[derive(Debug, Clone)]
+pub struct BankingInvariantReport {
+    pub expected_total: i64,
+    pub observed_total: i64,
+    pub negative_balances: Vec<(String, i64)>,
+}
+
+impl BankingInvariantReport {
+    /// The sum always has to match; negative balances only count against consistency when
+    /// `overdraft_allowed` was `false` for the run being checked.
+    pub fn is_consistent(&self, overdraft_allowed: bool) -> bool {
+        self.observed_total == self.expected_total && (overdraft_allowed || self.negative_balances.is_empty())
+    }
+
+    pub fn print_report(&self, overdraft_allowed: bool) {
+        println!("\n========== Banking Invariant Check ==========");
+        println!("Expected total: {}", self.expected_total);
+        println!("Observed total: {}", self.observed_total);
+        if self.observed_total == self.expected_total {
+            println!("Sum matches -- no write-skew/lost-update violation detected.");
+        } else {
+            println!("SUM MISMATCH -- isolation violation detected!");
+        }
+
+        if !overdraft_allowed {
+            println!("Negative balances: {}", self.negative_balances.len());
+            for (key, balance) in self.negative_balances.iter().take(10) {
+                println!("  {key} = {balance}");
+            }
+        }
+        println!("===============================================\n");
+    }
+}
+
+/// Sums the `num_accounts` banking keys present in `entries` (anything else the scan
+/// returned is ignored, including e.g. leftover keys from a prior non-banking run sharing
+/// the same prefix) and flags any that went negative.
+pub fn check_banking_invariant(
+    entries: &[(String, String)],
+    num_accounts: usize,
+    expected_total: i64,
+) -> BankingInvariantReport {
+    let balances: std::collections::HashMap<&str, i64> = entries
+        .iter()
+        .filter_map(|(key, value)| value.parse::<i64>().ok().map(|balance| (key.as_str(), balance)))
+        .collect();
+
+    let mut observed_total = 0i64;
+    let mut negative_balances = Vec::new();
+    for idx in 0..num_accounts {
+        let key = banking_account_key(idx);
+        let balance = balances.get(key.as_str()).copied().unwrap_or(0);
+        observed_total += balance;
+        if balance < 0 {
+            negative_balances.push((key, balance));
+        }
+    }
+
+    BankingInvariantReport {
+        expected_total,
+        observed_total,
+        negative_balances,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn balanced_ledger_is_consistent() {
+        let entries = vec![
+            (banking_account_key(0), "40".to_string()),
+            (banking_account_key(1), "60".to_string()),
+        ];
+        let report = check_banking_invariant(&entries, 2, 100);
+        assert!(report.is_consistent(false));
+    }
+
+    #[test]
+    fn sum_mismatch_is_flagged() {
+        let entries = vec![
+            (banking_account_key(0), "40".to_string()),
+            (banking_account_key(1), "55".to_string()),
+        ];
+        let report = check_banking_invariant(&entries, 2, 100);
+        assert!(!report.is_consistent(false));
+    }
+
+    #[test]
+    fn negative_balance_is_flagged_unless_overdraft_allowed() {
+        let entries = vec![
+            (banking_account_key(0), "-10".to_string()),
+            (banking_account_key(1), "110".to_string()),
+        ];
+        let report = check_banking_invariant(&entries, 2, 100);
+        assert!(!report.is_consistent(false));
+        assert!(report.is_consistent(true));
+    }
+
+    #[test]
+    fn missing_account_defaults_to_zero() {
+        let entries = vec![(banking_account_key(0), "100".to_string())];
+        let report = check_banking_invariant(&entries, 2, 100);
+        assert!(report.is_consistent(false));
+    }
+}
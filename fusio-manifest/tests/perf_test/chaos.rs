@@ -5,6 +5,9 @@ use std::sync::{
 use std::time::Duration;
 use tokio::task::JoinHandle;
 
+use crate::perf_test::multipart::MultipartFault;
+use crate::perf_test::toxiproxy::{ProxyToxics, ToxicProxy};
+
 #[derive(Debug, Clone)]
 pub enum ChaosScenario {
     None,
@@ -12,6 +15,26 @@ pub enum ChaosScenario {
     NetworkBlocking { block_duration_secs: u64, num_blocks: usize },
     CpuOverload { num_threads: usize, utilization_pct: u8 },
     Combined { delay_ms: u64, num_threads: usize, utilization_pct: u8 },
+    /// Randomly severs the response mid-transfer at the byte level, via [`ToxicProxy`].
+    PacketLoss { drop_pct: u8 },
+    /// Caps response byte-rate via [`ToxicProxy`], independent of `NetworkLatency`'s
+    /// fixed per-request sleep.
+    BandwidthCap { kbps: u64 },
+    /// Answers a configurable fraction of requests with a synthetic HTTP error (e.g. 500,
+    /// 503 "SlowDown") instead of forwarding to the real endpoint, via [`ToxicProxy`].
+    ServerErrors { status: u16, rate: f64 },
+    /// Aborts a multipart upload after this many parts have uploaded successfully.
+    MultipartPartAbort { after_part: usize },
+    /// Delays `CompleteMultipartUpload` by this many milliseconds.
+    MultipartCompleteDelay { delay_ms: u64 },
+    /// Drops a multipart upload session after this many parts, leaving it orphaned for
+    /// `MultipartRegistry::leaked_uploads` to catch.
+    MultipartSessionDrop { after_part: usize },
+    /// Skews even-numbered worker ids by `skew_ms`; see
+    /// [`ChaosController::apply_clock_skew`] for the delay that applies. Neither
+    /// `WorkloadDriver` nor `MockClient` calls `apply_clock_skew`, so a run under this
+    /// scenario leaves worker timing unchanged.
+    ClockSkew { skew_ms: i64 },
 }
 
 impl ChaosScenario {
@@ -28,6 +51,58 @@ impl ChaosScenario {
             ChaosScenario::Combined { delay_ms, num_threads, utilization_pct } => {
                 format!("combined-{}ms-{}threads-{}pct", delay_ms, num_threads, utilization_pct)
             }
+            ChaosScenario::PacketLoss { drop_pct } => format!("packet-loss-{}pct", drop_pct),
+            ChaosScenario::BandwidthCap { kbps } => format!("bandwidth-cap-{}kbps", kbps),
+            ChaosScenario::ServerErrors { status, rate } => {
+                format!("server-errors-{}-{:.0}pct", status, rate * 100.0)
+            }
+            ChaosScenario::MultipartPartAbort { after_part } => {
+                format!("multipart-abort-after-{}", after_part)
+            }
+            ChaosScenario::MultipartCompleteDelay { delay_ms } => {
+                format!("multipart-complete-delay-{}ms", delay_ms)
+            }
+            ChaosScenario::MultipartSessionDrop { after_part } => {
+                format!("multipart-drop-after-{}", after_part)
+            }
+            ChaosScenario::ClockSkew { skew_ms } => format!("clock-skew-{}ms", skew_ms),
+        }
+    }
+
+    /// The [`MultipartFault`] this scenario injects into a multipart upload, or `None` if
+    /// it isn't a multipart-targeted scenario.
+    pub fn multipart_fault(&self) -> Option<MultipartFault> {
+        match self {
+            ChaosScenario::MultipartPartAbort { after_part } => {
+                Some(MultipartFault::AbortAfterPart(*after_part))
+            }
+            ChaosScenario::MultipartCompleteDelay { delay_ms } => {
+                Some(MultipartFault::DelayCompleteMs(*delay_ms))
+            }
+            ChaosScenario::MultipartSessionDrop { after_part } => {
+                Some(MultipartFault::DropAfterPart(*after_part))
+            }
+            _ => None,
+        }
+    }
+
+    /// The `ProxyToxics` this scenario needs a [`ToxicProxy`] for, or `None` if it's a
+    /// purely local (non-network) scenario.
+    fn proxy_toxics(&self) -> Option<ProxyToxics> {
+        match self {
+            ChaosScenario::PacketLoss { drop_pct } => Some(ProxyToxics {
+                drop_pct: *drop_pct,
+                ..Default::default()
+            }),
+            ChaosScenario::BandwidthCap { kbps } => Some(ProxyToxics {
+                bandwidth_kbps: Some(*kbps),
+                ..Default::default()
+            }),
+            ChaosScenario::ServerErrors { status, rate } => Some(ProxyToxics {
+                server_error: Some((*status, *rate)),
+                ..Default::default()
+            }),
+            _ => None,
         }
     }
 }
@@ -37,6 +112,7 @@ pub struct ChaosController {
     running: Arc<AtomicBool>,
     cpu_handles: Vec<JoinHandle<()>>,
     blocking_handle: Option<JoinHandle<()>>,
+    proxy: Option<ToxicProxy>,
 }
 
 impl ChaosController {
@@ -46,6 +122,7 @@ impl ChaosController {
             running: Arc::new(AtomicBool::new(false)),
             cpu_handles: Vec::new(),
             blocking_handle: None,
+            proxy: None,
         }
     }
 
@@ -62,9 +139,31 @@ impl ChaosController {
             ChaosScenario::Combined { num_threads, utilization_pct, .. } => {
                 self.start_cpu_overload(*num_threads, *utilization_pct);
             }
+            ChaosScenario::PacketLoss { .. }
+            | ChaosScenario::BandwidthCap { .. }
+            | ChaosScenario::ServerErrors { .. } => {}
+            ChaosScenario::MultipartPartAbort { .. }
+            | ChaosScenario::MultipartCompleteDelay { .. }
+            | ChaosScenario::MultipartSessionDrop { .. } => {}
+            ChaosScenario::ClockSkew { .. } => {}
         }
     }
 
+    /// For scenarios that need real network impairment (`PacketLoss`, `BandwidthCap`,
+    /// `ServerErrors`), stands up a [`ToxicProxy`] in front of `upstream_addr` and returns
+    /// the local URL the client under test should be pointed at instead. Returns `None` for
+    /// scenarios that don't need a proxy, in which case the real endpoint should be used
+    /// unchanged.
+    pub async fn start_proxy(&mut self, upstream_addr: &str) -> Option<String> {
+        let toxics = self.scenario.proxy_toxics()?;
+        let proxy = ToxicProxy::start(upstream_addr.to_string(), toxics)
+            .await
+            .expect("failed to start toxic proxy");
+        let url = proxy.local_url();
+        self.proxy = Some(proxy);
+        Some(url)
+    }
+
     fn start_network_blocking(&mut self, block_duration_secs: u64, num_blocks: usize) {
         self.running.store(true, Ordering::SeqCst);
 
@@ -135,6 +234,24 @@ impl ChaosController {
         }
     }
 
+    /// Sleeps for `skew_ms` milliseconds when the scenario is `ClockSkew`, `worker_id` is
+    /// even, and `skew_ms` is positive. Odd worker ids get no delay, and neither does a
+    /// zero or negative `skew_ms`.
+    pub async fn apply_clock_skew(&self, worker_id: usize) {
+        if let Some(skew_ms) = self.get_clock_skew_ms(worker_id) {
+            if skew_ms > 0 {
+                tokio::time::sleep(Duration::from_millis(skew_ms as u64)).await;
+            }
+        }
+    }
+
+    fn get_clock_skew_ms(&self, worker_id: usize) -> Option<i64> {
+        match &self.scenario {
+            ChaosScenario::ClockSkew { skew_ms } if worker_id % 2 == 0 => Some(*skew_ms),
+            _ => None,
+        }
+    }
+
     pub async fn stop(mut self) {
         self.running.store(false, Ordering::SeqCst);
 
@@ -145,6 +262,10 @@ impl ChaosController {
         if let Some(handle) = self.blocking_handle.take() {
             let _ = handle.await;
         }
+
+        if let Some(proxy) = self.proxy.take() {
+            proxy.stop().await;
+        }
     }
 }
 
@@ -157,5 +278,12 @@ pub fn create_chaos_scenarios() -> Vec<ChaosScenario> {
         ChaosScenario::NetworkBlocking { block_duration_secs: 10, num_blocks: 3 },
         ChaosScenario::CpuOverload { num_threads: 4, utilization_pct: 80 },
         ChaosScenario::Combined { delay_ms: 200, num_threads: 4, utilization_pct: 80 },
+        ChaosScenario::PacketLoss { drop_pct: 5 },
+        ChaosScenario::BandwidthCap { kbps: 256 },
+        ChaosScenario::ServerErrors { status: 503, rate: 0.1 },
+        ChaosScenario::MultipartPartAbort { after_part: 2 },
+        ChaosScenario::MultipartCompleteDelay { delay_ms: 500 },
+        ChaosScenario::MultipartSessionDrop { after_part: 1 },
+        ChaosScenario::ClockSkew { skew_ms: 2000 },
     ]
 }
@@ -0,0 +1,161 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use sha2::{Digest, Sha256};
+
+/// How many checksums to mirror per key in `ChecksumRegistry`. A conditional write can
+/// overwrite a key while a reader's `session_read()` is still in flight against the prior
+/// version, so we keep the previous digest around for one generation rather than treating
+/// every such race as corruption.
+const HISTORY_PER_KEY: usize = 2;
+
+/// Digest algorithms modeled on S3's per-object checksum feature
+/// (`x-amz-checksum-crc32c` / `x-amz-checksum-sha256`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumAlgo {
+    Crc32c,
+    Sha256,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecksumEntry {
+    pub algo: ChecksumAlgo,
+    pub digest: String,
+    pub value_len: usize,
+}
+
+impl ChecksumEntry {
+    /// Computes the CRC32C digest over `value`, and additionally SHA-256 when
+    /// `include_sha256` is set (mirroring S3's "additional checksum" opt-in).
+    pub fn compute(value: &str, include_sha256: bool) -> Vec<Self> {
+        let bytes = value.as_bytes();
+        let mut entries = vec![Self {
+            algo: ChecksumAlgo::Crc32c,
+            digest: format!("{:08x}", crc32c::crc32c(bytes)),
+            value_len: bytes.len(),
+        }];
+
+        if include_sha256 {
+            let digest = Sha256::digest(bytes);
+            entries.push(Self {
+                algo: ChecksumAlgo::Sha256,
+                digest: format!("{:x}", digest),
+                value_len: bytes.len(),
+            });
+        }
+
+        entries
+    }
+
+    fn matches(&self, value: &str) -> bool {
+        match self.algo {
+            ChecksumAlgo::Crc32c => format!("{:08x}", crc32c::crc32c(value.as_bytes())) == self.digest,
+            ChecksumAlgo::Sha256 => format!("{:x}", Sha256::digest(value.as_bytes())) == self.digest,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ChecksumVerification {
+    /// The writer never recorded a checksum for this key (checksum verification was
+    /// enabled only partway through the run, or the key was never written by this harness).
+    NoChecksumRecorded,
+    Match,
+    Mismatch { expected: Vec<String>, actual: String },
+}
+
+/// Tracks `key -> (checksum_algo, digest, value_len)` for the latest accepted writes, so
+/// readers can verify retrieved bytes against what was actually written rather than trusting
+/// the backend blindly.
+pub struct ChecksumRegistry {
+    entries: Mutex<HashMap<String, VecDeque<Vec<ChecksumEntry>>>>,
+}
+
+impl ChecksumRegistry {
+    pub fn new() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Records the checksums for a successful write, keeping up to `HISTORY_PER_KEY`
+    /// generations so a reader racing an in-flight overwrite can match either the old or
+    /// new value.
+    pub fn record(&self, key: &str, checksums: Vec<ChecksumEntry>) {
+        let mut entries = self.entries.lock().unwrap();
+        let history = entries.entry(key.to_string()).or_default();
+        history.push_back(checksums);
+        while history.len() > HISTORY_PER_KEY {
+            history.pop_front();
+        }
+    }
+
+    /// Removes all tracked checksums for `key` (called when a writer deletes it).
+    pub fn remove(&self, key: &str) {
+        self.entries.lock().unwrap().remove(key);
+    }
+
+    /// Verifies `value` against every generation of checksum recorded for `key`. Any one
+    /// matching generation is accepted.
+    pub fn verify(&self, key: &str, value: &str) -> ChecksumVerification {
+        let entries = self.entries.lock().unwrap();
+        let Some(history) = entries.get(key) else {
+            return ChecksumVerification::NoChecksumRecorded;
+        };
+
+        for generation in history {
+            if generation.iter().all(|entry| entry.matches(value)) {
+                return ChecksumVerification::Match;
+            }
+        }
+
+        ChecksumVerification::Mismatch {
+            expected: history
+                .iter()
+                .flatten()
+                .map(|e| format!("{:?}:{}", e.algo, e.digest))
+                .collect(),
+            actual: format!("{:08x}", crc32c::crc32c(value.as_bytes())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_matches_recorded_checksum() {
+        let registry = ChecksumRegistry::new();
+        registry.record("k1", ChecksumEntry::compute("hello", true));
+        assert!(matches!(
+            registry.verify("k1", "hello"),
+            ChecksumVerification::Match
+        ));
+    }
+
+    #[test]
+    fn test_verify_detects_mismatch() {
+        let registry = ChecksumRegistry::new();
+        registry.record("k1", ChecksumEntry::compute("hello", false));
+        assert!(matches!(
+            registry.verify("k1", "goodbye"),
+            ChecksumVerification::Mismatch { .. }
+        ));
+    }
+
+    #[test]
+    fn test_verify_tolerates_prior_generation_during_overwrite_race() {
+        let registry = ChecksumRegistry::new();
+        registry.record("k1", ChecksumEntry::compute("v1", false));
+        registry.record("k1", ChecksumEntry::compute("v2", false));
+        assert!(matches!(
+            registry.verify("k1", "v1"),
+            ChecksumVerification::Match
+        ));
+        assert!(matches!(
+            registry.verify("k1", "v2"),
+            ChecksumVerification::Match
+        ));
+    }
+}
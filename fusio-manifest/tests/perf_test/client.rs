@@ -1,6 +1,5 @@
-use crate::perf_test::{metrics::MetricsCollector, utils::{KeyPool, KeyRegistry, WorkloadConfig}};
-use fusio::executor::tokio::TokioExecutor;
-use fusio_manifest::{s3::S3Manifest, types::Error};
+use crate::perf_test::{backend::ManifestBackend, checksum::{ChecksumEntry, ChecksumRegistry, ChecksumVerification}, dlq::{DlqEntry, DlqSink}, history::{OpKind, OperationLog}, metrics::MetricsCollector, multipart::{self, MultipartOutcome, MultipartRegistry}, overflow::GcraLimiter, utils::{banking_account_key, cores_for_worker, BatchKeyStrategy, KeyPool, KeyRegistry, WorkloadConfig, WorkloadMode}};
+use fusio_manifest::types::Error;
 use rand::{seq::SliceRandom, Rng};
 use std::{
     sync::Arc,
@@ -14,21 +13,27 @@ pub enum ClientType {
     Reader { id: usize },
 }
 
-pub struct MockClient {
+pub struct MockClient<B: ManifestBackend> {
     id: usize,
     client_type: ClientType,
-    manifest: Arc<S3Manifest<String, String, TokioExecutor>>,
+    manifest: Arc<B>,
     key_pool: Option<Arc<KeyPool>>,
     key_registry: Option<Arc<KeyRegistry>>,
+    hot_key_limiter: Option<Arc<GcraLimiter>>,
+    dlq: Option<Arc<dyn DlqSink>>,
+    checksums: Option<Arc<ChecksumRegistry>>,
+    history: Option<Arc<OperationLog>>,
+    multipart_registry: Option<Arc<MultipartRegistry>>,
     config: Arc<WorkloadConfig>,
     metrics: Arc<MetricsCollector>,
+    workload_name: Option<String>,
 }
 
-impl MockClient {
+impl<B: ManifestBackend> MockClient<B> {
     pub fn new(
         id: usize,
         client_type: ClientType,
-        manifest: Arc<S3Manifest<String, String, TokioExecutor>>,
+        manifest: Arc<B>,
         key_pool: Option<Arc<KeyPool>>,
         key_registry: Option<Arc<KeyRegistry>>,
         config: Arc<WorkloadConfig>,
@@ -40,23 +45,120 @@ impl MockClient {
             manifest,
             key_pool,
             key_registry,
+            hot_key_limiter: None,
+            dlq: None,
+            checksums: None,
+            history: None,
+            multipart_registry: None,
             config,
             metrics,
+            workload_name: None,
+        }
+    }
+
+    /// Tags this client's write/read records with `name` in `self.metrics`, for
+    /// `CombinationWorkload`'s per-leg `WorkloadBreakdown`. Unset by default, in which case
+    /// records go through untagged exactly as before. Batched commits and their precondition
+    /// failures (`run_batch_write_transaction`) and completed multipart uploads are recorded
+    /// untagged even when `name` is set, so they count only toward the aggregate.
+    pub fn with_workload_name(mut self, name: String) -> Self {
+        self.workload_name = Some(name);
+        self
+    }
+
+    /// Enables per-key GCRA overflow detection and hot-key rerouting on the `Writer` path.
+    pub fn with_hot_key_limiter(mut self, limiter: Arc<GcraLimiter>) -> Self {
+        self.hot_key_limiter = Some(limiter);
+        self
+    }
+
+    /// Captures transactions that exhaust their retries or hit a hard error into `sink`.
+    pub fn with_dlq_sink(mut self, sink: Arc<dyn DlqSink>) -> Self {
+        self.dlq = Some(sink);
+        self
+    }
+
+    /// Enables end-to-end checksum verification: writers record a digest of every value
+    /// they write, and readers flag a mismatch as a distinct failure class.
+    pub fn with_checksum_registry(mut self, registry: Arc<ChecksumRegistry>) -> Self {
+        self.checksums = Some(registry);
+        self
+    }
+
+    /// Records every write/read this client issues to `log`, for a post-run linearizability
+    /// check over the full operation history.
+    pub fn with_history_log(mut self, log: Arc<OperationLog>) -> Self {
+        self.history = Some(log);
+        self
+    }
+
+    /// Tracks multipart uploads this client initiates in `registry`, required when
+    /// `config.workload_mode` is `WorkloadMode::Multipart`.
+    pub fn with_multipart_registry(mut self, registry: Arc<MultipartRegistry>) -> Self {
+        self.multipart_registry = Some(registry);
+        self
+    }
+
+    /// Records checksums for a successfully written (non-delete) value, or forgets the
+    /// key's tracked checksums on delete.
+    fn record_write_checksum(&self, key: &str, value: &str, is_delete: bool) {
+        if let Some(registry) = &self.checksums {
+            if is_delete {
+                registry.remove(key);
+            } else {
+                registry.record(key, ChecksumEntry::compute(value, self.config.checksum_include_sha256));
+            }
+        }
+    }
+
+    /// Coordinated-omission correction interval for `rate`, or `None` if
+    /// `config.correct_coordinated_omission` is off. When set, `MetricsCollector` synthesizes
+    /// the intermediate samples a stalled request should have produced, instead of only
+    /// recording the one request that actually got to run.
+    fn expected_interval(&self, rate: f64) -> Option<Duration> {
+        self.config
+            .correct_coordinated_omission
+            .then(|| Duration::from_secs_f64(1.0 / rate))
+    }
+
+    /// Finishes the pending history entry for this transaction, if history tracking is
+    /// enabled. Consumes `pending` so it's only ever finished once per logical transaction,
+    /// no matter how many times the retry loop looped first.
+    fn record_history(
+        &self,
+        pending: &mut Option<crate::perf_test::history::PendingOp>,
+        key: String,
+        kind: OpKind,
+        value: Option<String>,
+        success: bool,
+    ) {
+        if let (Some(log), Some(pending)) = (&self.history, pending.take()) {
+            log.finish(pending, key, kind, value, success);
         }
     }
 
     #[tracing::instrument(skip(self), fields(writer_id = %self.id))]
-    async fn run_write_transaction(&mut self) -> Result<(), Error> {
+    async fn run_write_transaction(&self) -> Result<(), Error> {
         use rand::rngs::StdRng;
         use rand::SeedableRng;
 
         let key_pool = self.key_pool.as_ref().expect("KeyPool required for legacy Writer");
-        let my_keys = key_pool.writer_keys(self.id);
         let mut rng = StdRng::from_entropy();
-        let key = my_keys.choose(&mut rng).unwrap();
+        let mut key = key_pool.pick_writer_key(self.id, &mut rng).to_string();
 
         let mut attempt = 0;
+        let mut retry_counted = false;
+        let mut history_pending = self.history.as_ref().map(|log| log.begin());
         loop {
+            if let Some(limiter) = &self.hot_key_limiter {
+                if !limiter.check(&key) {
+                    self.metrics.record_hot_key_overflow();
+                    key = format!("{}_reroute_{}_{}", key, self.id, attempt);
+                    self.metrics.record_hot_key_reroute();
+                    tracing::debug!(writer_id = %self.id, key, "hot key overflow, rerouted to fresh key");
+                }
+            }
+
             let start = Instant::now();
 
             tracing::debug!(writer_id = %self.id, attempt, key, "starting write session");
@@ -85,12 +187,24 @@ impl MockClient {
                         latency_ms = latency.as_millis(),
                         "write committed successfully"
                     );
-                    self.metrics.record_write_success(latency, attempt);
+                    self.metrics.record_write_success_for(self.workload_name.as_deref(), self.id, latency, attempt, self.expected_interval(self.config.writer_rate));
+                    self.record_write_checksum(&key, &value, is_delete);
+                    self.record_history(
+                        &mut history_pending,
+                        key.clone(),
+                        if is_delete { OpKind::Delete } else { OpKind::Write },
+                        if is_delete { None } else { Some(value.clone()) },
+                        true,
+                    );
 
                     if !is_delete {
-                        self.metrics.record_successful_write(self.id, key.clone(), value);
+                        self.metrics
+                            .record_successful_write_for(self.workload_name.as_deref(), self.id, key.clone(), value);
                     }
 
+                    if retry_counted {
+                        self.metrics.dec_active_retry();
+                    }
                     return Ok(());
                 }
                 Err(Error::PreconditionFailed) => {
@@ -101,7 +215,11 @@ impl MockClient {
                         latency_ms = latency.as_millis(),
                         "PRECONDITION FAILURE - retrying"
                     );
-                    self.metrics.record_precondition_failure(latency, attempt);
+                    self.metrics.record_precondition_failure_for(self.workload_name.as_deref(), self.id, latency, attempt);
+                    if !retry_counted {
+                        self.metrics.inc_active_retry();
+                        retry_counted = true;
+                    }
 
                     if attempt >= self.config.max_retry_count {
                         tracing::error!(
@@ -110,6 +228,15 @@ impl MockClient {
                             "max retries exceeded"
                         );
                         self.metrics.record_max_retries_exceeded();
+                        self.capture_dead_letter(key.clone(), value.clone(), is_delete, attempt + 1, "max_retries_exceeded", None);
+                        self.record_history(
+                            &mut history_pending,
+                            key.clone(),
+                            if is_delete { OpKind::Delete } else { OpKind::Write },
+                            if is_delete { None } else { Some(value) },
+                            false,
+                        );
+                        self.metrics.dec_active_retry();
                         return Err(Error::PreconditionFailed);
                     }
                     attempt += 1;
@@ -117,21 +244,339 @@ impl MockClient {
                 }
                 Err(e) => {
                     tracing::error!(writer_id = %self.id, error = ?e, "write failed");
-                    self.metrics.record_write_error(latency);
+                    self.metrics.record_write_error_for(self.workload_name.as_deref(), self.id, latency);
+                    self.capture_dead_letter(key.clone(), value.clone(), is_delete, attempt + 1, &format!("{:?}", e), None);
+                    self.record_history(
+                        &mut history_pending,
+                        key.clone(),
+                        if is_delete { OpKind::Delete } else { OpKind::Write },
+                        if is_delete { None } else { Some(value) },
+                        false,
+                    );
+                    if retry_counted {
+                        self.metrics.dec_active_retry();
+                    }
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    /// Stages `config.ops_per_txn` puts/deletes across one or more keys in a single
+    /// `WriteSession`, then commits them all at once, amortizing commit overhead over
+    /// several ops instead of one per transaction.
+    #[tracing::instrument(skip(self), fields(writer_id = %self.id))]
+    async fn run_batch_write_transaction(&self) -> Result<(), Error> {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let key_pool = self.key_pool.as_ref().expect("KeyPool required for legacy Writer");
+        let mut rng = StdRng::from_entropy();
+        let ops_in_batch = self.config.ops_per_txn.max(1);
+
+        let mut attempt = 0;
+        let mut retry_counted = false;
+        loop {
+            let keys: Vec<String> = (0..ops_in_batch)
+                .map(|_| match self.config.batch_key_strategy {
+                    BatchKeyStrategy::SameWriterPool => {
+                        key_pool.pick_writer_key(self.id, &mut rng).to_string()
+                    }
+                    BatchKeyStrategy::Random => key_pool.pick_reader_key(&mut rng).to_string(),
+                })
+                .collect();
+
+            let start = Instant::now();
+
+            tracing::debug!(writer_id = %self.id, attempt, ops_in_batch, "starting batch write session");
+
+            let mut session = self.manifest.session_write().await?;
+
+            let mut ops: Vec<(String, Option<String>)> = Vec::with_capacity(keys.len());
+            for key in &keys {
+                if rng.gen::<f64>() < self.config.write_delete_ratio {
+                    session.delete(key.clone());
+                    ops.push((key.clone(), None));
+                } else {
+                    let value = generate_value(self.config.value_size);
+                    session.put(key.clone(), value.clone());
+                    ops.push((key.clone(), Some(value)));
+                }
+            }
+
+            let result = session.commit().await;
+            let latency = start.elapsed();
+
+            match result {
+                Ok(_) => {
+                    tracing::info!(
+                        writer_id = %self.id,
+                        attempt,
+                        ops_in_batch,
+                        latency_ms = latency.as_millis(),
+                        "batch write committed successfully"
+                    );
+                    self.metrics.record_batch_write_success(self.id, latency, attempt, ops_in_batch, self.expected_interval(self.config.writer_rate));
+                    for (key, value) in &ops {
+                        match value {
+                            Some(value) => self.record_write_checksum(key, value, false),
+                            None => self.record_write_checksum(key, "", true),
+                        }
+                    }
+                    if let Some(log) = &self.history {
+                        let complete = Instant::now();
+                        for (key, value) in &ops {
+                            let kind = if value.is_some() { OpKind::Write } else { OpKind::Delete };
+                            log.record(key.clone(), kind, start, complete, value.clone(), true);
+                        }
+                    }
+                    if retry_counted {
+                        self.metrics.dec_active_retry();
+                    }
+                    return Ok(());
+                }
+                Err(Error::PreconditionFailed) => {
+                    tracing::warn!(
+                        writer_id = %self.id,
+                        attempt,
+                        ops_in_batch,
+                        latency_ms = latency.as_millis(),
+                        "BATCH PRECONDITION FAILURE - retrying"
+                    );
+                    self.metrics.record_batch_precondition_failure(self.id, latency, attempt);
+                    if !retry_counted {
+                        self.metrics.inc_active_retry();
+                        retry_counted = true;
+                    }
+
+                    if attempt >= self.config.max_retry_count {
+                        tracing::error!(writer_id = %self.id, "batch write max retries exceeded");
+                        self.metrics.record_max_retries_exceeded();
+                        self.metrics.dec_active_retry();
+                        return Err(Error::PreconditionFailed);
+                    }
+                    attempt += 1;
+                    continue;
+                }
+                Err(e) => {
+                    tracing::error!(writer_id = %self.id, error = ?e, "batch write failed");
+                    self.metrics.record_write_error_for(self.workload_name.as_deref(), self.id, latency);
+                    if retry_counted {
+                        self.metrics.dec_active_retry();
+                    }
                     return Err(e);
                 }
             }
         }
     }
 
+    /// Runs one multipart upload (`config.workload_mode`'s `part_size`/`num_parts`) against
+    /// a key from this writer's pool, applying `config.multipart_fault` if one is set.
+    #[tracing::instrument(skip(self), fields(writer_id = %self.id))]
+    async fn run_multipart_write_transaction(&self) -> Result<(), Error> {
+        let WorkloadMode::Multipart { part_size, num_parts } = self.config.workload_mode else {
+            panic!("run_multipart_write_transaction requires WorkloadMode::Multipart");
+        };
+        let registry = self
+            .multipart_registry
+            .as_ref()
+            .expect("MultipartRegistry required for multipart workload mode");
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let key_pool = self.key_pool.as_ref().expect("KeyPool required for Multipart writer");
+        let mut rng = StdRng::from_entropy();
+        let key = key_pool.pick_writer_key(self.id, &mut rng).to_string();
+
+        let start = Instant::now();
+        tracing::debug!(writer_id = %self.id, key, part_size, num_parts, "starting multipart upload");
+
+        let outcome = multipart::run_multipart_upload(
+            self.manifest.as_ref(),
+            registry,
+            &key,
+            part_size,
+            num_parts,
+            self.config.multipart_fault,
+        )
+        .await?;
+        let latency = start.elapsed();
+
+        match outcome {
+            MultipartOutcome::Completed { etag } => {
+                tracing::info!(writer_id = %self.id, key, etag, latency_ms = latency.as_millis(), "multipart upload completed");
+                self.metrics.record_multipart_completed(self.id, latency, num_parts, self.expected_interval(self.config.writer_rate));
+                Ok(())
+            }
+            MultipartOutcome::Aborted => {
+                tracing::warn!(writer_id = %self.id, key, "multipart upload aborted by chaos fault");
+                let parts_uploaded = match self.config.multipart_fault {
+                    Some(multipart::MultipartFault::AbortAfterPart(after)) => after,
+                    _ => num_parts,
+                };
+                self.metrics.record_multipart_aborted(parts_uploaded);
+                Ok(())
+            }
+            MultipartOutcome::Dropped => {
+                tracing::warn!(writer_id = %self.id, key, "multipart upload session dropped by chaos fault");
+                let parts_uploaded = match self.config.multipart_fault {
+                    Some(multipart::MultipartFault::DropAfterPart(after)) => after,
+                    _ => num_parts,
+                };
+                self.metrics.record_multipart_dropped(parts_uploaded);
+                Ok(())
+            }
+        }
+    }
+
+    /// Transfer-ledger transaction for `WorkloadMode::Banking`: reads two distinct account
+    /// balances, debits one and credits the other by a random amount, and commits both
+    /// writes in a single session -- the same precondition/CAS retry path as
+    /// `run_write_transaction`, so a commit is rejected (and retried) if anything landed
+    /// since this transaction's read snapshot. Because the only way to corrupt the ledger
+    /// total is for two such transactions to interleave past that check, any final sum
+    /// mismatch (`crate::perf_test::banking::check_banking_invariant`) proves a write-skew
+    /// isolation violation.
+    #[tracing::instrument(skip(self), fields(writer_id = %self.id))]
+    async fn run_banking_transfer_transaction(&self) -> Result<(), Error> {
+        let WorkloadMode::Banking { num_accounts, max_transfer_amount, overdraft_allowed, .. } =
+            self.config.workload_mode
+        else {
+            panic!("run_banking_transfer_transaction requires WorkloadMode::Banking");
+        };
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::from_entropy();
+        let mut attempt = 0;
+
+        loop {
+            let from_idx = rng.gen_range(0..num_accounts);
+            let mut to_idx = rng.gen_range(0..num_accounts);
+            while to_idx == from_idx {
+                to_idx = rng.gen_range(0..num_accounts);
+            }
+            let from_key = banking_account_key(from_idx);
+            let to_key = banking_account_key(to_idx);
+
+            let start = Instant::now();
+
+            // Open the write session before reading the balances it will debit/credit, so its
+            // CAS base snapshot is never newer than what we read. Otherwise a transfer that
+            // commits in the gap between the read and the write-session open would advance the
+            // base out from under us, and our commit would succeed against stale balances.
+            let mut session = self.manifest.session_write().await?;
+
+            let reader = self.manifest.session_read().await?;
+            let from_balance: i64 = reader.get(&from_key).and_then(|v| v.parse().ok()).unwrap_or(0);
+            let to_balance: i64 = reader.get(&to_key).and_then(|v| v.parse().ok()).unwrap_or(0);
+
+            let max_amount = if overdraft_allowed {
+                max_transfer_amount
+            } else {
+                max_transfer_amount.min(from_balance)
+            };
+            if max_amount <= 0 {
+                tracing::debug!(writer_id = %self.id, from_key, "nothing transferable this round, skipping");
+                return Ok(());
+            }
+            let amount = rng.gen_range(1..=max_amount);
+
+            session.put(from_key.clone(), (from_balance - amount).to_string());
+            session.put(to_key.clone(), (to_balance + amount).to_string());
+
+            let result = session.commit().await;
+            let latency = start.elapsed();
+
+            match result {
+                Ok(_) => {
+                    tracing::info!(
+                        writer_id = %self.id,
+                        attempt,
+                        from_key,
+                        to_key,
+                        amount,
+                        latency_ms = latency.as_millis(),
+                        "transfer committed successfully"
+                    );
+                    self.metrics.record_write_success_for(self.workload_name.as_deref(), self.id, latency, attempt, self.expected_interval(self.config.writer_rate));
+                    return Ok(());
+                }
+                Err(Error::PreconditionFailed) => {
+                    tracing::warn!(writer_id = %self.id, attempt, from_key, to_key, "PRECONDITION FAILURE - retrying");
+                    self.metrics.record_precondition_failure_for(self.workload_name.as_deref(), self.id, latency, attempt);
+
+                    if attempt >= self.config.max_retry_count {
+                        tracing::error!(writer_id = %self.id, from_key, to_key, "max retries exceeded");
+                        self.metrics.record_max_retries_exceeded();
+                        self.capture_dead_letter(
+                            from_key.clone(),
+                            format!("transfer {amount} to {to_key}"),
+                            false,
+                            attempt + 1,
+                            "max_retries_exceeded",
+                            None,
+                        );
+                        return Err(Error::PreconditionFailed);
+                    }
+                    attempt += 1;
+                    continue;
+                }
+                Err(e) => {
+                    tracing::error!(writer_id = %self.id, error = ?e, "transfer failed");
+                    self.metrics.record_write_error_for(self.workload_name.as_deref(), self.id, latency);
+                    self.capture_dead_letter(
+                        from_key.clone(),
+                        format!("transfer {amount} to {to_key}"),
+                        false,
+                        attempt + 1,
+                        &format!("{:?}", e),
+                        None,
+                    );
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    /// Records a transaction that exhausted its retries or hit a hard error: always pushed
+    /// to `self.metrics`' bounded in-memory dead-letter buffer, and additionally to
+    /// `self.dlq` (a durable JSONL sink) if one is configured.
+    fn capture_dead_letter(
+        &self,
+        key: String,
+        intended_value: String,
+        is_delete: bool,
+        attempts: usize,
+        final_error: &str,
+        snapshot_txn_id: Option<u64>,
+    ) {
+        let entry = DlqEntry::new(
+            self.id,
+            key,
+            intended_value,
+            is_delete,
+            attempts,
+            final_error.to_string(),
+            snapshot_txn_id,
+        );
+
+        if let Some(dlq) = &self.dlq {
+            dlq.capture(entry.clone());
+        }
+        self.metrics.record_dead_letter(entry);
+    }
+
     #[tracing::instrument(skip(self), fields(monotonic_writer_id = %self.id))]
-    async fn run_monotonic_write_transaction(&mut self) -> Result<(), Error> {
+    async fn run_monotonic_write_transaction(&self) -> Result<(), Error> {
         let key_registry = self.key_registry.as_ref()
             .expect("KeyRegistry required for MonotonicWriter");
 
         let key = key_registry.allocate_next_key();
 
         let mut attempt = 0;
+        let mut retry_counted = false;
+        let mut history_pending = self.history.as_ref().map(|log| log.begin());
         loop {
             let start = Instant::now();
 
@@ -153,9 +598,15 @@ impl MockClient {
                         latency_ms = latency.as_millis(),
                         "monotonic write committed successfully"
                     );
-                    self.metrics.record_write_success(latency, attempt);
-                    self.metrics.record_successful_write(self.id, key.clone(), value.clone());
+                    self.metrics.record_write_success_for(self.workload_name.as_deref(), self.id, latency, attempt, self.expected_interval(self.config.writer_rate));
+                    self.metrics
+                        .record_successful_write_for(self.workload_name.as_deref(), self.id, key.clone(), value.clone());
+                    self.record_write_checksum(&key, &value, false);
+                    self.record_history(&mut history_pending, key.clone(), OpKind::Write, Some(value.clone()), true);
                     key_registry.register_written_key(key);
+                    if retry_counted {
+                        self.metrics.dec_active_retry();
+                    }
                     return Ok(());
                 }
                 Err(Error::PreconditionFailed) => {
@@ -166,7 +617,11 @@ impl MockClient {
                         latency_ms = latency.as_millis(),
                         "PRECONDITION FAILURE - retrying"
                     );
-                    self.metrics.record_precondition_failure(latency, attempt);
+                    self.metrics.record_precondition_failure_for(self.workload_name.as_deref(), self.id, latency, attempt);
+                    if !retry_counted {
+                        self.metrics.inc_active_retry();
+                        retry_counted = true;
+                    }
 
                     if attempt >= self.config.max_retry_count {
                         tracing::error!(
@@ -175,6 +630,9 @@ impl MockClient {
                             "max retries exceeded"
                         );
                         self.metrics.record_max_retries_exceeded();
+                        self.capture_dead_letter(key.clone(), value.clone(), false, attempt + 1, "max_retries_exceeded", None);
+                        self.record_history(&mut history_pending, key.clone(), OpKind::Write, Some(value.clone()), false);
+                        self.metrics.dec_active_retry();
                         return Err(Error::PreconditionFailed);
                     }
                     attempt += 1;
@@ -182,7 +640,12 @@ impl MockClient {
                 }
                 Err(e) => {
                     tracing::error!(writer_id = %self.id, error = ?e, "monotonic write failed");
-                    self.metrics.record_write_error(latency);
+                    self.metrics.record_write_error_for(self.workload_name.as_deref(), self.id, latency);
+                    self.capture_dead_letter(key.clone(), value.clone(), false, attempt + 1, &format!("{:?}", e), None);
+                    self.record_history(&mut history_pending, key.clone(), OpKind::Write, Some(value.clone()), false);
+                    if retry_counted {
+                        self.metrics.dec_active_retry();
+                    }
                     return Err(e);
                 }
             }
@@ -190,44 +653,67 @@ impl MockClient {
     }
 
     #[tracing::instrument(skip(self), fields(reader_id = %self.id))]
-    async fn run_read_transaction(&mut self) -> Result<(), Error> {
+    async fn run_read_transaction(&self) -> Result<(), Error> {
         use rand::rngs::StdRng;
         use rand::SeedableRng;
 
         let mut rng = StdRng::from_entropy();
 
-        let all_keys = if let Some(ref key_pool) = self.key_pool {
-            key_pool.reader_keys().to_vec()
+        let key = if let Some(ref key_pool) = self.key_pool {
+            if key_pool.reader_keys().is_empty() {
+                tracing::debug!(reader_id = %self.id, "no keys available to read, skipping");
+                return Ok(());
+            }
+            key_pool.pick_reader_key(&mut rng).to_string()
         } else if let Some(ref key_registry) = self.key_registry {
-            key_registry.all_keys()
+            let all_keys = key_registry.all_keys();
+            if all_keys.is_empty() {
+                tracing::debug!(reader_id = %self.id, "no keys available to read, skipping");
+                return Ok(());
+            }
+            all_keys.choose(&mut rng).unwrap().clone()
         } else {
             tracing::error!("No key source available for reader");
             return Ok(());
         };
-
-        if all_keys.is_empty() {
-            tracing::debug!(reader_id = %self.id, "no keys available to read, skipping");
-            return Ok(());
-        }
-
-        let key = all_keys.choose(&mut rng).unwrap();
+        let key = &key;
 
         let start = Instant::now();
         tracing::debug!(reader_id = %self.id, key, "starting read session");
 
         let session = self.manifest.session_read().await?;
-        let snapshot_txn_id = session.snapshot().txn_id.0;
-        let value = session.get(key).await?;
+        let snapshot_txn_id = session.snapshot_txn_id();
+        let value = session.get(key);
+
+        if let (Some(registry), Some(value)) = (&self.checksums, &value) {
+            match registry.verify(key, value) {
+                ChecksumVerification::Mismatch { expected, actual } => {
+                    tracing::error!(
+                        reader_id = %self.id,
+                        key,
+                        expected = ?expected,
+                        actual,
+                        "CHECKSUM MISMATCH - retrieved value does not match any recorded digest"
+                    );
+                    self.metrics.record_checksum_mismatch();
+                }
+                ChecksumVerification::Match | ChecksumVerification::NoChecksumRecorded => {}
+            }
+        }
+
+        if let Some(log) = &self.history {
+            let complete = Instant::now();
+            log.record(key.clone(), OpKind::Read, start, complete, value.clone(), true);
+        }
 
-        self.metrics.record_read_observation(
+        self.metrics.record_read_observation_for(
+            self.workload_name.as_deref(),
             self.id,
             snapshot_txn_id,
             key.clone(),
             value,
         );
 
-        session.end().await?;
-
         let latency = start.elapsed();
         tracing::debug!(
             reader_id = %self.id,
@@ -236,41 +722,160 @@ impl MockClient {
             "read completed"
         );
 
-        self.metrics.record_read(latency);
+        self.metrics.record_read_for(self.workload_name.as_deref(), self.id, latency, self.expected_interval(self.config.reader_rate));
         Ok(())
     }
 
-    pub async fn run_loop(&mut self, duration: Duration) {
-        let rate = match self.client_type {
+    /// Configured operation rate for this client's role (`writer_rate` for both writer
+    /// variants, `reader_rate` for readers).
+    fn rate(&self) -> f64 {
+        match self.client_type {
             ClientType::MonotonicWriter { .. } => self.config.writer_rate,
             ClientType::Writer { .. } => self.config.writer_rate,
             ClientType::Reader { .. } => self.config.reader_rate,
+        }
+    }
+
+    /// Pins this worker's current OS thread to a host CPU core selected by
+    /// `config.cpu_affinity`, for reproducible scheduler placement, and records the core
+    /// actually applied to `metrics` for provenance. A no-op if no rule covers `self.id` or
+    /// the host doesn't expose core ids.
+    fn pin_to_assigned_cpu(&self) {
+        let Some(cores) = cores_for_worker(&self.config.cpu_affinity, self.id) else {
+            return;
         };
+        let Some(core_ids) = core_affinity::get_core_ids() else {
+            return;
+        };
+        let Some(core) = core_ids.into_iter().find(|c| cores.contains(&c.id)) else {
+            return;
+        };
+        if core_affinity::set_for_current(core) {
+            self.metrics.record_worker_cpu(self.id, core.id);
+        }
+    }
 
-        let interval = Duration::from_secs_f64(1.0 / rate);
+    /// Dispatches the one transaction kind this client's `ClientType`/`config` selects,
+    /// logging (rather than propagating) a failure so a caller looping over many iterations
+    /// doesn't need its own error handling.
+    async fn run_one_transaction(&self) {
+        let result = match self.client_type {
+            ClientType::MonotonicWriter { .. } => self.run_monotonic_write_transaction().await,
+            ClientType::Writer { .. } if matches!(self.config.workload_mode, WorkloadMode::Multipart { .. }) => {
+                self.run_multipart_write_transaction().await
+            }
+            ClientType::Writer { .. } if matches!(self.config.workload_mode, WorkloadMode::Banking { .. }) => {
+                self.run_banking_transfer_transaction().await
+            }
+            ClientType::Writer { .. } if self.config.ops_per_txn > 1 => self.run_batch_write_transaction().await,
+            ClientType::Writer { .. } => self.run_write_transaction().await,
+            ClientType::Reader { .. } => self.run_read_transaction().await,
+        };
+
+        if let Err(e) = result {
+            tracing::error!(client_id = %self.id, error = ?e, "transaction failed");
+        }
+    }
+
+    /// Runs transactions on a fixed-rate ticker until `duration` elapses or `shutdown`
+    /// reports true, whichever comes first. Each iteration always awaits its transaction
+    /// to completion before checking shutdown again, so an in-flight commit is never
+    /// abandoned mid-write. This is closed-loop: under saturation the achieved rate quietly
+    /// falls below `rate()` instead of queueing, since the next tick only starts once the
+    /// previous transaction has returned. See `run_open_loop` for the alternative.
+    pub async fn run_loop(&self, duration: Duration, mut shutdown: tokio::sync::watch::Receiver<bool>) {
+        self.pin_to_assigned_cpu();
+
+        let interval = Duration::from_secs_f64(1.0 / self.rate());
         let mut ticker = tokio::time::interval(interval);
         let deadline = Instant::now() + duration;
 
         loop {
-            ticker.tick().await;
+            tokio::select! {
+                _ = ticker.tick() => {}
+                _ = shutdown.changed() => {}
+            }
 
-            if Instant::now() >= deadline {
+            if *shutdown.borrow() || Instant::now() >= deadline {
+                tracing::debug!(client_id = %self.id, "run_loop stopping, no new transactions will be issued");
                 break;
             }
 
-            let result = match self.client_type {
-                ClientType::MonotonicWriter { .. } => self.run_monotonic_write_transaction().await,
-                ClientType::Writer { .. } => self.run_write_transaction().await,
-                ClientType::Reader { .. } => self.run_read_transaction().await,
-            };
+            self.run_one_transaction().await;
+        }
+    }
+
+    /// Open-loop variant of `run_loop`: arrival times are drawn ahead of time from a Poisson
+    /// process with mean inter-arrival `1 / rate()` and each transaction is dispatched the
+    /// instant its arrival time comes due, as a separate spawned task, without waiting for
+    /// earlier transactions to finish -- bounded to `max_in_flight` concurrently running
+    /// transactions via a semaphore. Requires `self` behind an `Arc` since transactions now
+    /// run concurrently against the one shared client.
+    ///
+    /// The gap between an arrival's scheduled time and the moment it actually acquires a
+    /// semaphore permit and starts running is recorded to `MetricsCollector` as queueing
+    /// delay: a backend that can't keep up with `rate()` shows up as growing delay here
+    /// instead of a silently-throttled throughput number.
+    pub async fn run_open_loop(
+        self: Arc<Self>,
+        duration: Duration,
+        max_in_flight: usize,
+        mut shutdown: tokio::sync::watch::Receiver<bool>,
+    ) where
+        B: 'static,
+    {
+        self.pin_to_assigned_cpu();
+
+        let semaphore = Arc::new(tokio::sync::Semaphore::new(max_in_flight));
+        let deadline = Instant::now() + duration;
+        let mut rng = {
+            use rand::SeedableRng;
+            rand::rngs::StdRng::from_entropy()
+        };
 
-            if let Err(e) = result {
-                tracing::error!(client_id = %self.id, error = ?e, "transaction failed");
+        let mut next_arrival = Instant::now();
+        let mut in_flight = Vec::new();
+
+        loop {
+            next_arrival += exponential_inter_arrival(&mut rng, self.rate());
+            if next_arrival >= deadline {
+                break;
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep_until(next_arrival.into()) => {}
+                _ = shutdown.changed() => {}
+            }
+            if *shutdown.borrow() {
+                break;
             }
+
+            let Ok(permit) = semaphore.clone().acquire_owned().await else {
+                break;
+            };
+            let scheduled = next_arrival;
+
+            let client = self.clone();
+            in_flight.push(tokio::spawn(async move {
+                let _permit = permit;
+                let dispatch_lag = Instant::now().saturating_duration_since(scheduled);
+                client.metrics.record_queueing_delay(dispatch_lag);
+                client.run_one_transaction().await;
+            }));
         }
+
+        tracing::debug!(client_id = %self.id, "run_open_loop stopping, draining in-flight transactions");
+        futures_util::future::join_all(in_flight).await;
     }
 }
 
+/// Draws one Poisson-process inter-arrival time with mean `1 / rate`, via inverse-transform
+/// sampling of the exponential distribution.
+fn exponential_inter_arrival(rng: &mut impl Rng, rate: f64) -> Duration {
+    let u: f64 = rng.gen_range(f64::EPSILON..1.0);
+    Duration::from_secs_f64(-u.ln() / rate)
+}
+
 fn generate_value(size: usize) -> String {
     use rand::distributions::Alphanumeric;
     use rand::rngs::StdRng;
@@ -0,0 +1,162 @@
+//! Mixed-workload mode: several named `WorkloadConfig`s run concurrently against one shared
+//! manifest, each confined to a disjoint slice of the key space so one leg's precondition
+//! failures/retries can't leak into or inflate another's numbers. Lets a caller measure, for
+//! example, how a high-contention writer pool affects a low-rate scanner sharing the same
+//! manifest, which a single homogeneous `WorkloadDriver` run can't show.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::perf_test::{
+    backend::ManifestBackend,
+    client::{ClientType, MockClient},
+    metrics::{MetricsCollector, MetricsSummary},
+    utils::{KeyPool, WorkloadConfig},
+    workload::spawn_client_loop,
+};
+
+/// One leg of a `CombinationWorkload`: a `name` every write/read it issues is tagged with in
+/// the shared `MetricsCollector` (see `MetricsSummary::per_workload`), and the `WorkloadConfig`
+/// driving its own writers/readers.
+#[derive(Debug, Clone)]
+pub struct NamedWorkload {
+    pub name: String,
+    pub config: WorkloadConfig,
+}
+
+impl NamedWorkload {
+    pub fn new(name: impl Into<String>, config: WorkloadConfig) -> Self {
+        Self { name: name.into(), config }
+    }
+}
+
+/// Runs several `NamedWorkload` legs concurrently against one shared manifest. Each leg gets
+/// its own `KeyPool` carved out of a disjoint, non-overlapping slice of the key space (via
+/// `KeyPool::new_with_offset`), and a single shared `MetricsCollector` tags every record with
+/// its originating leg name so `MetricsSummary::per_workload` can report per-leg TPS/latency/
+/// failure-rate in addition to the run's aggregate.
+pub struct CombinationWorkload<B: ManifestBackend + 'static> {
+    manifest: Arc<B>,
+    metrics: Arc<MetricsCollector>,
+    legs: Vec<NamedWorkload>,
+}
+
+impl<B: ManifestBackend + 'static> CombinationWorkload<B> {
+    pub fn new(manifest: Arc<B>, legs: Vec<NamedWorkload>) -> Self {
+        Self {
+            manifest,
+            metrics: Arc::new(MetricsCollector::new()),
+            legs,
+        }
+    }
+
+    pub fn metrics(&self) -> &Arc<MetricsCollector> {
+        &self.metrics
+    }
+
+    /// Runs every leg's writers/readers concurrently until the longest leg's `duration`
+    /// elapses, then returns the shared aggregate summary (its `per_workload` field carries
+    /// each leg's breakdown). A leg whose own `duration` is shorter simply stops issuing new
+    /// transactions once it elapses, same as `WorkloadDriver::run` does for a single config.
+    pub async fn run(&self) -> MetricsSummary {
+        let (shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
+        let mut handles = Vec::new();
+        let mut key_offset = 0;
+
+        for leg in &self.legs {
+            let key_pool = Arc::new(KeyPool::new_with_offset(
+                key_offset,
+                leg.config.key_pool_size,
+                leg.config.num_writers,
+                leg.config.key_overlap_ratio,
+                leg.config.key_distribution,
+            ));
+            key_offset += leg.config.key_pool_size;
+
+            for writer_id in 0..leg.config.num_writers {
+                let client = MockClient::new(
+                    writer_id,
+                    ClientType::Writer { id: writer_id },
+                    self.manifest.clone(),
+                    Some(key_pool.clone()),
+                    None,
+                    Arc::new(leg.config.clone()),
+                    self.metrics.clone(),
+                )
+                .with_workload_name(leg.name.clone());
+
+                handles.push(spawn_client_loop(
+                    client,
+                    leg.config.arrival_mode,
+                    leg.config.duration,
+                    shutdown_rx.clone(),
+                ));
+            }
+
+            for reader_id in 0..leg.config.num_readers {
+                let client = MockClient::new(
+                    reader_id,
+                    ClientType::Reader { id: reader_id },
+                    self.manifest.clone(),
+                    Some(key_pool.clone()),
+                    None,
+                    Arc::new(leg.config.clone()),
+                    self.metrics.clone(),
+                )
+                .with_workload_name(leg.name.clone());
+
+                handles.push(spawn_client_loop(
+                    client,
+                    leg.config.arrival_mode,
+                    leg.config.duration,
+                    shutdown_rx.clone(),
+                ));
+            }
+        }
+
+        let longest = self
+            .legs
+            .iter()
+            .map(|leg| leg.config.duration)
+            .max()
+            .unwrap_or(Duration::ZERO);
+        tokio::time::sleep(longest).await;
+        shutdown_tx.send_replace(true);
+
+        futures_util::future::join_all(handles).await;
+
+        self.metrics.summary()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::perf_test::utils::KeyDistribution;
+
+    #[test]
+    fn named_workload_legs_get_disjoint_key_pools() {
+        let legs = vec![
+            NamedWorkload::new("writers", WorkloadConfig { key_pool_size: 50, num_writers: 1, ..WorkloadConfig::default() }),
+            NamedWorkload::new("scanners", WorkloadConfig { key_pool_size: 50, num_writers: 1, ..WorkloadConfig::default() }),
+        ];
+
+        let mut key_offset = 0;
+        let mut all_keys = std::collections::HashSet::new();
+        for leg in &legs {
+            let pool = KeyPool::new_with_offset(
+                key_offset,
+                leg.config.key_pool_size,
+                leg.config.num_writers,
+                leg.config.key_overlap_ratio,
+                KeyDistribution::Uniform,
+            );
+            for key in pool.reader_keys() {
+                assert!(all_keys.insert(key.clone()), "key {key} reused across legs");
+            }
+            key_offset += leg.config.key_pool_size;
+        }
+
+        assert_eq!(all_keys.len(), 100);
+    }
+}
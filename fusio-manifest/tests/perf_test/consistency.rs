@@ -0,0 +1,272 @@
+//! Post-run consistency checker over the write/read records recorded by
+//! [`crate::perf_test::metrics::MetricsCollector`], distinct from
+//! [`crate::perf_test::linearizability`]'s op-history check: this one is built for ordinary
+//! workload runs where `history_tracking` is off but `MetricsCollector` is always populated,
+//! and it checks against snapshot semantics (`snapshot_txn_id`) rather than real-time
+//! `[invoke, complete]` intervals.
+//!
+//! `InMemoryManifest`/`S3Manifest` share one monotonic, whole-manifest txn id: `snapshot_txn_id`
+//! on a `ReadSession` is the number of commits that had landed, across every key, by the time
+//! the snapshot was taken. That means a write's position in commit order (its rank among all
+//! `WriteRecord`s sorted by timestamp) is exactly the txn id in effect right after it commits,
+//! so a read's snapshot boundary can be located in a key's timeline with a binary search instead
+//! of needing the manifest to expose per-write txn ids directly.
+//!
+//! That rank-equals-txn-id equivalence only holds for single-op `WholeObject` runs: a commit
+//! bumps the shared txn id by exactly 1 no matter how many keys it touches, so a multi-op
+//! transaction (`WorkloadMode::Banking`, any `ops_per_txn > 1`) emits several `WriteRecord`s per
+//! txn id bump and rank runs ahead of the real txn id. `WorkloadDriver::check_consistency` gates
+//! on this and returns `None` outside that single-op whole-object case rather than reporting
+//! spurious violations. Deletes break the equivalence the same way -- a committed delete bumps
+//! the txn id but records no `WriteRecord` -- so the gate also requires `write_delete_ratio`
+//! to be zero.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::perf_test::metrics::{ReadRecord, WriteRecord};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViolationKind {
+    /// The read observed a value that was never written to this key in any commit.
+    NeverWritten,
+    /// The read observed a value that didn't commit until after the read's snapshot txn id --
+    /// a future write leaking into a past snapshot.
+    FutureRead,
+    /// A newer value had already committed by the read's snapshot boundary, but the read
+    /// returned an older one.
+    StaleRead,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConsistencyViolation {
+    pub kind: ViolationKind,
+    pub key: String,
+    pub reader_id: usize,
+    pub snapshot_txn_id: u64,
+    pub observed: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ConsistencyReport {
+    pub reads_checked: usize,
+    pub violations: Vec<ConsistencyViolation>,
+}
+
+impl ConsistencyReport {
+    pub fn never_written_count(&self) -> usize {
+        self.count(ViolationKind::NeverWritten)
+    }
+
+    pub fn future_read_count(&self) -> usize {
+        self.count(ViolationKind::FutureRead)
+    }
+
+    pub fn stale_read_count(&self) -> usize {
+        self.count(ViolationKind::StaleRead)
+    }
+
+    fn count(&self, kind: ViolationKind) -> usize {
+        self.violations.iter().filter(|v| v.kind == kind).count()
+    }
+
+    pub fn is_consistent(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Prints a summary plus up to `examples_per_kind` example records for each violation
+    /// class, so a failing run points straight at a reproducible offender instead of just a
+    /// count.
+    pub fn print_report(&self, examples_per_kind: usize) {
+        println!("\n========== Consistency Check ==========");
+        println!("Reads checked:     {}", self.reads_checked);
+        println!("Never-written:     {}", self.never_written_count());
+        println!("Future reads:      {}", self.future_read_count());
+        println!("Stale reads:       {}", self.stale_read_count());
+
+        for kind in [ViolationKind::NeverWritten, ViolationKind::FutureRead, ViolationKind::StaleRead] {
+            let examples: Vec<&ConsistencyViolation> = self
+                .violations
+                .iter()
+                .filter(|v| v.kind == kind)
+                .take(examples_per_kind)
+                .collect();
+            if examples.is_empty() {
+                continue;
+            }
+            println!("\n--- {kind:?} examples ---");
+            for v in examples {
+                println!(
+                    "  reader={} key={} snapshot_txn_id={} observed={:?}",
+                    v.reader_id, v.key, v.snapshot_txn_id, v.observed
+                );
+            }
+        }
+        println!("========================================\n");
+    }
+}
+
+/// One key's committed values in commit order, each tagged with the txn id in effect right
+/// after it landed.
+struct CommittedValue {
+    rank: u64,
+    value: String,
+    #[allow(dead_code)]
+    timestamp: Instant,
+}
+
+/// Groups `writes` by key and assigns each one its global commit rank (1-based position when
+/// every write, across all keys, is sorted by timestamp), which -- per `InMemoryManifest`'s
+/// single shared txn counter -- is exactly the txn id a reader's snapshot must be at or past to
+/// have observed it.
+fn build_timelines(writes: &[WriteRecord]) -> HashMap<&str, Vec<CommittedValue>> {
+    let mut ordered: Vec<&WriteRecord> = writes.iter().collect();
+    ordered.sort_by_key(|w| w.timestamp);
+
+    let mut timelines: HashMap<&str, Vec<CommittedValue>> = HashMap::new();
+    for (idx, write) in ordered.into_iter().enumerate() {
+        timelines
+            .entry(write.key.as_str())
+            .or_default()
+            .push(CommittedValue {
+                rank: (idx + 1) as u64,
+                value: write.value.clone(),
+                timestamp: write.timestamp,
+            });
+    }
+    timelines
+}
+
+/// Classifies a single read against its key's commit timeline, or `None` if the observation is
+/// consistent with the read's snapshot.
+fn classify(timeline: &[CommittedValue], read: &ReadRecord) -> Option<ViolationKind> {
+    let expected_idx = timeline.partition_point(|entry| entry.rank <= read.snapshot_txn_id);
+    let expected = expected_idx.checked_sub(1).map(|i| &timeline[i]);
+
+    let matches_expected = match (&read.value, expected) {
+        (None, None) => true,
+        (Some(observed), Some(entry)) => *observed == entry.value,
+        _ => false,
+    };
+    if matches_expected {
+        return None;
+    }
+
+    // `WorkloadDriver::check_consistency` only runs this checker on delete-free workloads, so
+    // an observed `None` against a timeline that expects a value falls outside the three
+    // classes this checker covers and is not flagged.
+    let observed = read.value.as_ref()?;
+
+    match timeline.iter().find(|entry| &entry.value == observed) {
+        None => Some(ViolationKind::NeverWritten),
+        Some(matched) if matched.rank > read.snapshot_txn_id => Some(ViolationKind::FutureRead),
+        // `matched` committed at or before the snapshot boundary but isn't `expected`, so it
+        // must have a lower rank than `expected` -- a strictly newer value was already
+        // committed by the time this snapshot was taken.
+        Some(_) => Some(ViolationKind::StaleRead),
+    }
+}
+
+/// Checks every recorded read against the committed write history for its key, flagging reads
+/// that observed a value inconsistent with the optimistic-concurrency manifest's snapshot
+/// semantics.
+pub fn check_consistency(writes: &[WriteRecord], reads: &[ReadRecord]) -> ConsistencyReport {
+    let timelines = build_timelines(writes);
+    let empty: Vec<CommittedValue> = Vec::new();
+
+    let violations = reads
+        .iter()
+        .filter_map(|read| {
+            let timeline = timelines.get(read.key.as_str()).unwrap_or(&empty);
+            classify(timeline, read).map(|kind| ConsistencyViolation {
+                kind,
+                key: read.key.clone(),
+                reader_id: read.reader_id,
+                snapshot_txn_id: read.snapshot_txn_id,
+                observed: read.value.clone(),
+            })
+        })
+        .collect();
+
+    ConsistencyReport {
+        reads_checked: reads.len(),
+        violations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn write(key: &str, value: &str, base: Instant, offset_ms: u64) -> WriteRecord {
+        WriteRecord {
+            writer_id: 0,
+            key: key.to_string(),
+            value: value.to_string(),
+            timestamp: base + Duration::from_millis(offset_ms),
+            workload: None,
+        }
+    }
+
+    fn read(key: &str, snapshot_txn_id: u64, value: Option<&str>) -> ReadRecord {
+        ReadRecord {
+            reader_id: 0,
+            snapshot_txn_id,
+            key: key.to_string(),
+            value: value.map(str::to_string),
+            timestamp: Instant::now(),
+            workload: None,
+        }
+    }
+
+    #[test]
+    fn read_at_snapshot_boundary_is_consistent() {
+        let base = Instant::now();
+        let writes = vec![write("k", "a", base, 0), write("k", "b", base, 10)];
+        let reads = vec![read("k", 1, Some("a")), read("k", 2, Some("b"))];
+
+        let report = check_consistency(&writes, &reads);
+        assert!(report.is_consistent());
+        assert_eq!(report.reads_checked, 2);
+    }
+
+    #[test]
+    fn read_of_never_written_value_is_flagged() {
+        let base = Instant::now();
+        let writes = vec![write("k", "a", base, 0)];
+        let reads = vec![read("k", 1, Some("ghost"))];
+
+        let report = check_consistency(&writes, &reads);
+        assert_eq!(report.never_written_count(), 1);
+    }
+
+    #[test]
+    fn read_of_future_write_is_flagged() {
+        let base = Instant::now();
+        let writes = vec![write("k", "a", base, 0), write("k", "b", base, 10)];
+        // Snapshot taken right after "a" committed, but the read observed "b".
+        let reads = vec![read("k", 1, Some("b"))];
+
+        let report = check_consistency(&writes, &reads);
+        assert_eq!(report.future_read_count(), 1);
+    }
+
+    #[test]
+    fn stale_read_behind_its_own_snapshot_is_flagged() {
+        let base = Instant::now();
+        let writes = vec![write("k", "a", base, 0), write("k", "b", base, 10)];
+        // Snapshot txn id 2 means "b" had already committed, but the read returned "a".
+        let reads = vec![read("k", 2, Some("a"))];
+
+        let report = check_consistency(&writes, &reads);
+        assert_eq!(report.stale_read_count(), 1);
+    }
+
+    #[test]
+    fn read_of_unwritten_key_before_any_commit_is_consistent() {
+        let reads = vec![read("k", 0, None)];
+        let report = check_consistency(&[], &reads);
+        assert!(report.is_consistent());
+    }
+}
@@ -0,0 +1,147 @@
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::perf_test::backend::ManifestBackend;
+
+/// A captured transaction that exhausted its retries or hit a hard error, durable enough
+/// to survive the process and be replayed later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DlqEntry {
+    pub client_id: usize,
+    pub key: String,
+    pub intended_value: String,
+    pub is_delete: bool,
+    pub attempts: usize,
+    pub final_error: String,
+    pub snapshot_txn_id: Option<u64>,
+    pub timestamp_secs: u64,
+}
+
+impl DlqEntry {
+    pub fn new(
+        client_id: usize,
+        key: String,
+        intended_value: String,
+        is_delete: bool,
+        attempts: usize,
+        final_error: String,
+        snapshot_txn_id: Option<u64>,
+    ) -> Self {
+        Self {
+            client_id,
+            key,
+            intended_value,
+            is_delete,
+            attempts,
+            final_error,
+            snapshot_txn_id,
+            timestamp_secs: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+        }
+    }
+}
+
+/// Durable sink for exhausted/hard-errored transactions.
+pub trait DlqSink: Send + Sync {
+    fn capture(&self, entry: DlqEntry);
+}
+
+/// JSONL-file-backed `DlqSink`: one `DlqEntry` per line, appended as failures occur.
+pub struct JsonlDlqSink {
+    file: Mutex<File>,
+}
+
+impl JsonlDlqSink {
+    pub fn new(path: &str) -> std::io::Result<Self> {
+        if let Some(parent) = std::path::Path::new(path).parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            file: Mutex::new(file),
+        })
+    }
+}
+
+impl DlqSink for JsonlDlqSink {
+    fn capture(&self, entry: DlqEntry) {
+        let line = serde_json::to_string(&entry).expect("DlqEntry is always serializable");
+        let mut file = self.file.lock().unwrap();
+        if let Err(e) = writeln!(file, "{}", line) {
+            tracing::error!(error = ?e, "failed to append DLQ entry");
+        }
+    }
+}
+
+pub fn read_dlq_file(path: &str) -> Result<Vec<DlqEntry>, Box<dyn std::error::Error>> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    let mut entries = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        entries.push(serde_json::from_str(&line)?);
+    }
+    Ok(entries)
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DlqReplayReport {
+    pub total: usize,
+    pub succeeded: usize,
+    pub still_conflicting: usize,
+    pub hard_errors: usize,
+}
+
+impl DlqReplayReport {
+    pub fn print_report(&self) {
+        println!("\n=== DLQ Replay Report ===");
+        println!("Total replayed:     {}", self.total);
+        println!("Succeeded:          {}", self.succeeded);
+        println!("Still conflicting:  {}", self.still_conflicting);
+        println!("Hard errors:        {}", self.hard_errors);
+        println!("==========================\n");
+    }
+}
+
+/// Re-runs each captured DLQ entry once against `manifest`, reporting how many would now
+/// succeed. A high success rate indicates the original failures were transient contention
+/// rather than real data loss.
+pub async fn replay_dlq<B: ManifestBackend>(entries: Vec<DlqEntry>, manifest: &B) -> DlqReplayReport {
+    let mut report = DlqReplayReport {
+        total: entries.len(),
+        ..Default::default()
+    };
+
+    for entry in entries {
+        let mut session = match manifest.session_write().await {
+            Ok(session) => session,
+            Err(_) => {
+                report.hard_errors += 1;
+                continue;
+            }
+        };
+
+        if entry.is_delete {
+            session.delete(entry.key.clone());
+        } else {
+            session.put(entry.key.clone(), entry.intended_value.clone());
+        }
+
+        match session.commit().await {
+            Ok(_) => report.succeeded += 1,
+            Err(fusio_manifest::types::Error::PreconditionFailed) => report.still_conflicting += 1,
+            Err(_) => report.hard_errors += 1,
+        }
+    }
+
+    report
+}
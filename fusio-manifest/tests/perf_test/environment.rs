@@ -0,0 +1,66 @@
+//! Captures the environment a sweep ran under -- git commit/describe, rustc version, hostname,
+//! CPU count, and the S3 target (bucket/endpoint/region) -- so a `ResultsStore` row can be
+//! traced back to exactly the code and target it measured, not just the config under test.
+
+use std::env;
+use std::process::Command;
+
+use crate::perf_test::utils::load_aws_credentials;
+
+/// Snapshot of the environment a sweep ran under, captured once per process and stamped onto
+/// every `ResultsStore::record` call in that run.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RunEnvironment {
+    pub git_commit: Option<String>,
+    pub git_describe: Option<String>,
+    pub rustc_version: Option<String>,
+    pub hostname: Option<String>,
+    pub cpu_count: usize,
+    pub s3_bucket: String,
+    pub s3_endpoint: Option<String>,
+    pub s3_region: Option<String>,
+}
+
+impl RunEnvironment {
+    /// Captures the current process's environment. Any individual probe that fails (no `git`
+    /// on `PATH`, no AWS credentials configured, ...) degrades to `None` rather than failing
+    /// the whole capture -- a sweep should still record and compare on what it *can* determine.
+    pub fn capture() -> Self {
+        Self {
+            git_commit: run_command("git", &["rev-parse", "HEAD"]),
+            git_describe: run_command("git", &["describe", "--always", "--dirty"]),
+            rustc_version: run_command("rustc", &["--version"]),
+            hostname: run_command("hostname", &[]),
+            cpu_count: std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+            s3_bucket: env::var("FUSIO_MANIFEST_BUCKET").unwrap_or_else(|_| "liguoso-tonbo-s3".to_string()),
+            s3_endpoint: env::var("AWS_ENDPOINT_URL").ok(),
+            s3_region: load_aws_credentials().ok().map(|creds| creds.region),
+        }
+    }
+}
+
+fn run_command(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let trimmed = String::from_utf8(output.stdout).ok()?;
+    let trimmed = trimmed.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capture_always_resolves_cpu_count_and_s3_bucket() {
+        let env = RunEnvironment::capture();
+        assert!(env.cpu_count >= 1);
+        assert!(!env.s3_bucket.is_empty());
+    }
+}
@@ -0,0 +1,135 @@
+//! Records a shared, timestamped operation history of writes/reads so a run can be checked
+//! for linearizability afterwards (see [`crate::perf_test::linearizability`]). Each entry
+//! captures the `[invoke, complete]` real-time interval the operation spanned plus the value
+//! it wrote or observed, which is all the register-consistency checker needs.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpKind {
+    Read,
+    Write,
+    Delete,
+}
+
+#[derive(Debug, Clone)]
+pub struct OpRecord {
+    pub op_id: u64,
+    pub key: String,
+    pub kind: OpKind,
+    pub invoke_ts: Instant,
+    pub complete_ts: Instant,
+    /// The value written (for a committed `Write`) or observed (for a `Read`). `None` for a
+    /// committed `Delete`, or a `Read` that found no value for the key.
+    pub value: Option<String>,
+    /// Whether a `Write`/`Delete` actually committed. Always `true` for `Read`, since a read
+    /// that errors before observing anything isn't recorded at all.
+    pub success: bool,
+}
+
+/// A handle allocated by [`OperationLog::begin`] at the moment an operation is invoked.
+/// Thread it through to [`OperationLog::finish`] once the operation completes -- on a writer
+/// that retries, call `begin` once per logical transaction, not once per attempt, so the
+/// recorded interval spans the whole retry loop as observed by the rest of the system.
+pub struct PendingOp {
+    op_id: u64,
+    invoke_ts: Instant,
+}
+
+pub struct OperationLog {
+    next_op_id: AtomicU64,
+    entries: Mutex<Vec<OpRecord>>,
+}
+
+impl OperationLog {
+    pub fn new() -> Self {
+        Self {
+            next_op_id: AtomicU64::new(0),
+            entries: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn begin(&self) -> PendingOp {
+        PendingOp {
+            op_id: self.next_op_id.fetch_add(1, Ordering::Relaxed),
+            invoke_ts: Instant::now(),
+        }
+    }
+
+    pub fn finish(&self, pending: PendingOp, key: String, kind: OpKind, value: Option<String>, success: bool) {
+        self.entries.lock().unwrap().push(OpRecord {
+            op_id: pending.op_id,
+            key,
+            kind,
+            invoke_ts: pending.invoke_ts,
+            complete_ts: Instant::now(),
+            value,
+            success,
+        });
+    }
+
+    /// Records an entry with an explicit `[invoke_ts, complete_ts]` interval, for batched
+    /// transactions where several keys share one commit's timing but each still needs its
+    /// own per-key entry for the per-key linearizability check.
+    #[allow(clippy::too_many_arguments)]
+    pub fn record(
+        &self,
+        key: String,
+        kind: OpKind,
+        invoke_ts: Instant,
+        complete_ts: Instant,
+        value: Option<String>,
+        success: bool,
+    ) {
+        let op_id = self.next_op_id.fetch_add(1, Ordering::Relaxed);
+        self.entries.lock().unwrap().push(OpRecord {
+            op_id,
+            key,
+            kind,
+            invoke_ts,
+            complete_ts,
+            value,
+            success,
+        });
+    }
+
+    /// A snapshot of the history recorded so far, for feeding into
+    /// [`crate::perf_test::linearizability::check_history`].
+    pub fn snapshot(&self) -> Vec<OpRecord> {
+        self.entries.lock().unwrap().clone()
+    }
+}
+
+impl Default for OperationLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_entry_spanning_invoke_to_finish() {
+        let log = OperationLog::new();
+        let pending = log.begin();
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        log.finish(pending, "k1".to_string(), OpKind::Write, Some("v1".to_string()), true);
+
+        let snapshot = log.snapshot();
+        assert_eq!(snapshot.len(), 1);
+        assert!(snapshot[0].complete_ts > snapshot[0].invoke_ts);
+        assert_eq!(snapshot[0].op_id, 0);
+    }
+
+    #[test]
+    fn allocates_distinct_op_ids() {
+        let log = OperationLog::new();
+        let a = log.begin();
+        let b = log.begin();
+        assert_ne!(a.op_id, b.op_id);
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    collections::{HashMap, VecDeque},
     sync::{
         atomic::{AtomicU64, Ordering},
         Arc, Mutex,
@@ -8,12 +9,39 @@ use std::{
 
 use hdrhistogram::Histogram;
 
+use crate::perf_test::dlq::DlqEntry;
+use crate::perf_test::metrics_sink::MetricsSink;
+
+/// Default cap on `MetricsCollector`'s in-memory dead-letter buffer when a workload doesn't
+/// override it via `WorkloadConfig::dlq_capacity`.
+pub const DEFAULT_DLQ_CAPACITY: usize = 1000;
+
+/// Records `latency` into `hist`, correcting for coordinated omission when
+/// `expected_interval` is `Some`: if `latency` exceeds the interval requests at that rate
+/// should have been spaced by, this is HdrHistogram's "record with expected interval"
+/// behavior -- it backfills the samples the stalled requests that never got issued would
+/// have produced, instead of only recording the one request that actually ran.
+fn record_with_correction(hist: &mut Histogram<u64>, latency: Duration, expected_interval: Option<Duration>) {
+    let value = latency.as_micros() as u64;
+    match expected_interval {
+        Some(interval) if !interval.is_zero() => {
+            hist.record_correct(value, interval.as_micros() as u64).ok();
+        }
+        _ => {
+            hist.record(value).ok();
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct WriteRecord {
     pub writer_id: usize,
     pub key: String,
     pub value: String,
     pub timestamp: Instant,
+    /// The `CombinationWorkload` leg name this write was issued under, or `None` for an
+    /// ordinary single-`WorkloadConfig` run.
+    pub workload: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -23,12 +51,41 @@ pub struct ReadRecord {
     pub key: String,
     pub value: Option<String>,
     pub timestamp: Instant,
+    /// The `CombinationWorkload` leg name this read was issued under, or `None` for an
+    /// ordinary single-`WorkloadConfig` run.
+    pub workload: Option<String>,
+}
+
+/// Per-leg counters/histogram for a `CombinationWorkload`, keyed by `NamedWorkload::name` in
+/// `MetricsCollector::workload_stats`. Mirrors the subset of the collector's top-level fields
+/// needed for `WorkloadBreakdown`, kept separate so an ordinary run (no tagged records) pays
+/// no locking/bookkeeping cost for this.
+#[derive(Debug, Clone)]
+struct WorkloadCounters {
+    writes_attempted: u64,
+    writes_succeeded: u64,
+    precondition_failures: u64,
+    reads: u64,
+    write_hist: Histogram<u64>,
+}
+
+impl WorkloadCounters {
+    fn new() -> Self {
+        Self {
+            writes_attempted: 0,
+            writes_succeeded: 0,
+            precondition_failures: 0,
+            reads: 0,
+            write_hist: Histogram::<u64>::new(3).unwrap(),
+        }
+    }
 }
 
 pub struct MetricsCollector {
     write_success_latency: Arc<Mutex<Histogram<u64>>>,
     precondition_failure_latency: Arc<Mutex<Histogram<u64>>>,
     read_latency: Arc<Mutex<Histogram<u64>>>,
+    queueing_delay: Arc<Mutex<Histogram<u64>>>,
 
     total_writes_attempted: AtomicU64,
     total_writes_succeeded: AtomicU64,
@@ -44,14 +101,77 @@ pub struct MetricsCollector {
 
     total_retry_failures: AtomicU64,
     total_max_retries_exceeded: AtomicU64,
+
+    total_hot_key_overflows: AtomicU64,
+    total_hot_key_reroutes: AtomicU64,
+
+    total_batch_ops_committed: AtomicU64,
+    total_batch_commits_succeeded: AtomicU64,
+    total_batch_precondition_failures: AtomicU64,
+
+    active_retries: AtomicU64,
+    last_rolling_sample: Mutex<RollingSampleState>,
+    last_interval_sample: Mutex<IntervalSampleState>,
+    interval_summaries: Mutex<Vec<IntervalSummary>>,
+
+    total_checksum_mismatches: AtomicU64,
+
+    total_multipart_uploads_completed: AtomicU64,
+    total_multipart_uploads_aborted: AtomicU64,
+    total_multipart_uploads_dropped: AtomicU64,
+    total_multipart_parts_uploaded: AtomicU64,
+
+    sinks: Mutex<Vec<Arc<dyn MetricsSink>>>,
+
+    dead_letters: Mutex<VecDeque<DlqEntry>>,
+    dead_letter_capacity: usize,
+    total_dead_letters_dropped: AtomicU64,
+
+    worker_cpu: Mutex<Vec<(usize, usize)>>,
+
+    /// Per-leg counters for a `CombinationWorkload`, keyed by `NamedWorkload::name`. Stays
+    /// empty (no locking beyond the initial check) for an ordinary run that never tags a
+    /// record with a workload name.
+    workload_stats: Mutex<HashMap<String, WorkloadCounters>>,
+}
+
+#[derive(Debug, Clone)]
+struct RollingSampleState {
+    at: Instant,
+    writes_succeeded: u64,
+    writes_attempted: u64,
+    precondition_failures: u64,
+    reads: u64,
+    write_hist: Histogram<u64>,
+}
+
+/// The cumulative histograms/counters as of the start of the current reporting window,
+/// so `sample_interval` can diff against them instead of recomputing from scratch.
+#[derive(Debug, Clone)]
+struct IntervalSampleState {
+    at: Instant,
+    write_hist: Histogram<u64>,
+    precond_hist: Histogram<u64>,
+    read_hist: Histogram<u64>,
+    writes_succeeded: u64,
+    writes_attempted: u64,
+    precondition_failures: u64,
+    reads: u64,
 }
 
 impl MetricsCollector {
     pub fn new() -> Self {
+        Self::with_dead_letter_capacity(DEFAULT_DLQ_CAPACITY)
+    }
+
+    /// Like [`Self::new`], but with an explicit cap on the in-memory dead-letter buffer
+    /// (see [`Self::record_dead_letter`]) instead of [`DEFAULT_DLQ_CAPACITY`].
+    pub fn with_dead_letter_capacity(dead_letter_capacity: usize) -> Self {
         Self {
             write_success_latency: Arc::new(Mutex::new(Histogram::<u64>::new(3).unwrap())),
             precondition_failure_latency: Arc::new(Mutex::new(Histogram::<u64>::new(3).unwrap())),
             read_latency: Arc::new(Mutex::new(Histogram::<u64>::new(3).unwrap())),
+            queueing_delay: Arc::new(Mutex::new(Histogram::<u64>::new(3).unwrap())),
             total_writes_attempted: AtomicU64::new(0),
             total_writes_succeeded: AtomicU64::new(0),
             total_precondition_failures: AtomicU64::new(0),
@@ -63,15 +183,285 @@ impl MetricsCollector {
             reader_observations: Arc::new(Mutex::new(Vec::new())),
             total_retry_failures: AtomicU64::new(0),
             total_max_retries_exceeded: AtomicU64::new(0),
+            total_hot_key_overflows: AtomicU64::new(0),
+            total_hot_key_reroutes: AtomicU64::new(0),
+            total_batch_ops_committed: AtomicU64::new(0),
+            total_batch_commits_succeeded: AtomicU64::new(0),
+            total_batch_precondition_failures: AtomicU64::new(0),
+            active_retries: AtomicU64::new(0),
+            last_rolling_sample: Mutex::new(RollingSampleState {
+                at: Instant::now(),
+                writes_succeeded: 0,
+                writes_attempted: 0,
+                precondition_failures: 0,
+                reads: 0,
+                write_hist: Histogram::<u64>::new(3).unwrap(),
+            }),
+            last_interval_sample: Mutex::new(IntervalSampleState {
+                at: Instant::now(),
+                write_hist: Histogram::<u64>::new(3).unwrap(),
+                precond_hist: Histogram::<u64>::new(3).unwrap(),
+                read_hist: Histogram::<u64>::new(3).unwrap(),
+                writes_succeeded: 0,
+                writes_attempted: 0,
+                precondition_failures: 0,
+                reads: 0,
+            }),
+            interval_summaries: Mutex::new(Vec::new()),
+            total_checksum_mismatches: AtomicU64::new(0),
+            total_multipart_uploads_completed: AtomicU64::new(0),
+            total_multipart_uploads_aborted: AtomicU64::new(0),
+            total_multipart_uploads_dropped: AtomicU64::new(0),
+            total_multipart_parts_uploaded: AtomicU64::new(0),
+            sinks: Mutex::new(Vec::new()),
+            dead_letters: Mutex::new(VecDeque::new()),
+            dead_letter_capacity,
+            total_dead_letters_dropped: AtomicU64::new(0),
+            worker_cpu: Mutex::new(Vec::new()),
+            workload_stats: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Updates (creating if absent) the `WorkloadCounters` entry for `workload`, a no-op if
+    /// `workload` is `None`.
+    fn record_workload(&self, workload: Option<&str>, f: impl FnOnce(&mut WorkloadCounters)) {
+        let Some(name) = workload else { return };
+        let mut stats = self.workload_stats.lock().unwrap();
+        f(stats.entry(name.to_string()).or_insert_with(WorkloadCounters::new));
+    }
+
+    /// Registers a fan-out destination for live counters/timings (StatsD, Prometheus, ...).
+    /// Every `record_*` call after this pushes to `sink` in addition to the local histograms.
+    pub fn add_sink(&self, sink: Arc<dyn MetricsSink>) {
+        self.sinks.lock().unwrap().push(sink);
+    }
+
+    fn fan_out_counter(&self, name: &str, value: u64, tags: &[(&str, &str)]) {
+        for sink in self.sinks.lock().unwrap().iter() {
+            sink.incr_counter(name, value, tags);
+        }
+    }
+
+    fn fan_out_timing(&self, name: &str, duration: Duration, tags: &[(&str, &str)]) {
+        for sink in self.sinks.lock().unwrap().iter() {
+            sink.record_timing(name, duration, tags);
         }
     }
 
+    /// Records a reader detecting a checksum mismatch, a distinct failure class from a
+    /// precondition failure: the read itself succeeded, but the returned bytes don't match
+    /// any digest recorded for this key.
+    pub fn record_checksum_mismatch(&self) {
+        self.total_checksum_mismatches.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records a multipart upload reaching `CompleteMultipartUpload`, covering `num_parts`
+    /// part uploads.
+    pub fn record_multipart_completed(
+        &self,
+        writer_id: usize,
+        latency: Duration,
+        num_parts: usize,
+        expected_interval: Option<Duration>,
+    ) {
+        self.total_multipart_uploads_completed
+            .fetch_add(1, Ordering::Relaxed);
+        self.total_multipart_parts_uploaded
+            .fetch_add(num_parts as u64, Ordering::Relaxed);
+        self.record_write_success(writer_id, latency, 0, expected_interval);
+    }
+
+    pub fn record_multipart_aborted(&self, parts_uploaded: usize) {
+        self.total_multipart_uploads_aborted
+            .fetch_add(1, Ordering::Relaxed);
+        self.total_multipart_parts_uploaded
+            .fetch_add(parts_uploaded as u64, Ordering::Relaxed);
+    }
+
+    pub fn record_multipart_dropped(&self, parts_uploaded: usize) {
+        self.total_multipart_uploads_dropped
+            .fetch_add(1, Ordering::Relaxed);
+        self.total_multipart_parts_uploaded
+            .fetch_add(parts_uploaded as u64, Ordering::Relaxed);
+    }
+
+    /// Marks one writer as having entered its retry loop (called once per transaction, on
+    /// its first `PreconditionFailed`). Paired with [`Self::dec_active_retry`] whenever the
+    /// transaction ultimately resolves.
+    pub fn inc_active_retry(&self) {
+        self.active_retries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn dec_active_retry(&self) {
+        self.active_retries.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// Computes instantaneous write/read TPS, rolling write-latency p50/p99, precondition-
+    /// failure rate (both windowed and cumulative), and in-flight retry count since the
+    /// previous call, for live progress reporting. The first call after construction reports
+    /// against the collector's start time.
+    pub fn sample_rolling_window(&self) -> RollingSnapshot {
+        let now = Instant::now();
+        let writes_succeeded = self.total_writes_succeeded.load(Ordering::Relaxed);
+        let writes_attempted = self.total_writes_attempted.load(Ordering::Relaxed);
+        let precondition_failures = self.total_precondition_failures.load(Ordering::Relaxed);
+        let reads = self.total_reads.load(Ordering::Relaxed);
+        let cumulative_write_hist = self.write_success_latency.lock().unwrap().clone();
+
+        let mut last = self.last_rolling_sample.lock().unwrap();
+        let elapsed = now.duration_since(last.at).as_secs_f64().max(0.001);
+
+        let mut window_write_hist = cumulative_write_hist.clone();
+        window_write_hist.subtract(&last.write_hist).ok();
+
+        let snapshot = RollingSnapshot {
+            write_tps: (writes_succeeded - last.writes_succeeded) as f64 / elapsed,
+            read_tps: (reads - last.reads) as f64 / elapsed,
+            write_p50_ms: window_write_hist.value_at_quantile(0.5) as f64 / 1000.0,
+            write_p99_ms: window_write_hist.value_at_quantile(0.99) as f64 / 1000.0,
+            precondition_failure_rate: {
+                let window_attempts = writes_attempted - last.writes_attempted;
+                let window_precond = precondition_failures - last.precondition_failures;
+                if window_attempts > 0 {
+                    window_precond as f64 / window_attempts as f64
+                } else {
+                    0.0
+                }
+            },
+            cumulative_precondition_failure_rate: if writes_attempted > 0 {
+                precondition_failures as f64 / writes_attempted as f64
+            } else {
+                0.0
+            },
+            in_flight_retries: self.active_retries.load(Ordering::Relaxed),
+        };
+
+        *last = RollingSampleState {
+            at: now,
+            writes_succeeded,
+            writes_attempted,
+            precondition_failures,
+            reads,
+            write_hist: cumulative_write_hist,
+        };
+
+        snapshot
+    }
+
+    /// Diffs the cumulative histograms/counters against the previous call (or against
+    /// construction time, for the first call) to produce per-window p50/p95/p99 and a
+    /// window TPS, without double-counting across overlapping windows. Each call also
+    /// appends the resulting `IntervalSummary` to `interval_summaries()`.
+    pub fn sample_interval(&self) -> IntervalSummary {
+        let now = Instant::now();
+
+        let writes_succeeded = self.total_writes_succeeded.load(Ordering::Relaxed);
+        let writes_attempted = self.total_writes_attempted.load(Ordering::Relaxed);
+        let precondition_failures = self.total_precondition_failures.load(Ordering::Relaxed);
+        let reads = self.total_reads.load(Ordering::Relaxed);
+
+        let cumulative_write_hist = self.write_success_latency.lock().unwrap().clone();
+        let cumulative_precond_hist = self.precondition_failure_latency.lock().unwrap().clone();
+        let cumulative_read_hist = self.read_latency.lock().unwrap().clone();
+
+        let mut last = self.last_interval_sample.lock().unwrap();
+        let elapsed = now.duration_since(last.at).as_secs_f64().max(0.001);
+
+        let mut window_write_hist = cumulative_write_hist.clone();
+        window_write_hist.subtract(&last.write_hist).ok();
+        let mut window_precond_hist = cumulative_precond_hist.clone();
+        window_precond_hist.subtract(&last.precond_hist).ok();
+        let mut window_read_hist = cumulative_read_hist.clone();
+        window_read_hist.subtract(&last.read_hist).ok();
+
+        let window_writes_succeeded = writes_succeeded - last.writes_succeeded;
+        let window_writes_attempted = writes_attempted - last.writes_attempted;
+        let window_precondition_failures = precondition_failures - last.precondition_failures;
+        let window_reads = reads - last.reads;
+
+        let summary = IntervalSummary {
+            elapsed_since_start: now.duration_since(self.start_time),
+            window_duration: Duration::from_secs_f64(elapsed),
+            write_tps: window_writes_succeeded as f64 / elapsed,
+            read_tps: window_reads as f64 / elapsed,
+            precondition_failure_rate: if window_writes_attempted > 0 {
+                window_precondition_failures as f64 / window_writes_attempted as f64
+            } else {
+                0.0
+            },
+            write_p50_ms: window_write_hist.value_at_quantile(0.5) as f64 / 1000.0,
+            write_p95_ms: window_write_hist.value_at_quantile(0.95) as f64 / 1000.0,
+            write_p99_ms: window_write_hist.value_at_quantile(0.99) as f64 / 1000.0,
+            read_p50_ms: window_read_hist.value_at_quantile(0.5) as f64 / 1000.0,
+            read_p99_ms: window_read_hist.value_at_quantile(0.99) as f64 / 1000.0,
+        };
+
+        *last = IntervalSampleState {
+            at: now,
+            write_hist: cumulative_write_hist,
+            precond_hist: cumulative_precond_hist,
+            read_hist: cumulative_read_hist,
+            writes_succeeded,
+            writes_attempted,
+            precondition_failures,
+            reads,
+        };
+        drop(last);
+
+        self.interval_summaries.lock().unwrap().push(summary);
+        summary
+    }
+
+    /// The full stream of windows recorded so far via `sample_interval`, for plotting a
+    /// time series of throughput/latency over the life of a run.
+    pub fn interval_summaries(&self) -> Vec<IntervalSummary> {
+        self.interval_summaries.lock().unwrap().clone()
+    }
+
+    /// Records a batched write transaction that committed successfully, covering
+    /// `ops_in_batch` staged puts/deletes in a single `commit()`.
+    pub fn record_batch_write_success(
+        &self,
+        writer_id: usize,
+        latency: Duration,
+        retry_count: usize,
+        ops_in_batch: usize,
+        expected_interval: Option<Duration>,
+    ) {
+        self.total_batch_commits_succeeded
+            .fetch_add(1, Ordering::Relaxed);
+        self.total_batch_ops_committed
+            .fetch_add(ops_in_batch as u64, Ordering::Relaxed);
+        self.record_write_success(writer_id, latency, retry_count, expected_interval);
+    }
+
+    pub fn record_batch_precondition_failure(&self, writer_id: usize, latency: Duration, retry_count: usize) {
+        self.total_batch_precondition_failures
+            .fetch_add(1, Ordering::Relaxed);
+        self.record_precondition_failure(writer_id, latency, retry_count);
+    }
+
+    pub fn record_hot_key_overflow(&self) {
+        self.total_hot_key_overflows.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_hot_key_reroute(&self) {
+        self.total_hot_key_reroutes.fetch_add(1, Ordering::Relaxed);
+    }
+
     pub fn record_successful_write(&self, writer_id: usize, key: String, value: String) {
+        self.record_successful_write_for(None, writer_id, key, value);
+    }
+
+    /// Like [`Self::record_successful_write`], tagging the record with `workload` (see
+    /// `CombinationWorkload`).
+    pub fn record_successful_write_for(&self, workload: Option<&str>, writer_id: usize, key: String, value: String) {
         self.successful_writes.lock().unwrap().push(WriteRecord {
             writer_id,
             key,
             value,
             timestamp: Instant::now(),
+            workload: workload.map(str::to_string),
         });
     }
 
@@ -81,6 +471,19 @@ impl MetricsCollector {
         snapshot_txn_id: u64,
         key: String,
         value: Option<String>,
+    ) {
+        self.record_read_observation_for(None, reader_id, snapshot_txn_id, key, value);
+    }
+
+    /// Like [`Self::record_read_observation`], tagging the record with `workload` (see
+    /// `CombinationWorkload`).
+    pub fn record_read_observation_for(
+        &self,
+        workload: Option<&str>,
+        reader_id: usize,
+        snapshot_txn_id: u64,
+        key: String,
+        value: Option<String>,
     ) {
         self.reader_observations.lock().unwrap().push(ReadRecord {
             reader_id,
@@ -88,6 +491,7 @@ impl MetricsCollector {
             key,
             value,
             timestamp: Instant::now(),
+            workload: workload.map(str::to_string),
         });
     }
 
@@ -99,18 +503,90 @@ impl MetricsCollector {
         self.reader_observations.lock().unwrap().clone()
     }
 
-    pub fn record_write_success(&self, latency: Duration, retry_count: usize) {
+    /// Captures a transaction that exhausted its retries or hit a hard error, evicting the
+    /// oldest entry if the buffer is already at `dead_letter_capacity` so a pathological run
+    /// can't grow this unboundedly.
+    pub fn record_dead_letter(&self, entry: DlqEntry) {
+        let mut dead_letters = self.dead_letters.lock().unwrap();
+        if dead_letters.len() >= self.dead_letter_capacity {
+            dead_letters.pop_front();
+            self.total_dead_letters_dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        dead_letters.push_back(entry);
+    }
+
+    /// Takes every dead letter captured so far, leaving the buffer empty.
+    pub fn drain_dead_letters(&self) -> Vec<DlqEntry> {
+        self.dead_letters.lock().unwrap().drain(..).collect()
+    }
+
+    /// Number of dead letters evicted to stay within `dead_letter_capacity`, i.e. entries
+    /// lost without ever being drained.
+    pub fn total_dead_letters_dropped(&self) -> u64 {
+        self.total_dead_letters_dropped.load(Ordering::Relaxed)
+    }
+
+    /// Write attempts plus reads so far, the closest proxy available to a raw S3 request
+    /// count since the harness doesn't track individual S3 API calls separately -- used by
+    /// `profiler::SysMonitorProfiler`'s time series.
+    pub fn approx_s3_request_count(&self) -> u64 {
+        self.total_writes_attempted.load(Ordering::Relaxed) + self.total_reads.load(Ordering::Relaxed)
+    }
+
+    /// Records a successful write's latency. When `expected_interval` is `Some` (i.e.
+    /// `config.correct_coordinated_omission` is on), applies HdrHistogram's standard
+    /// coordinated-omission correction: if `latency` exceeds the interval the writer should
+    /// have issued requests at, this also synthesizes the samples a stalled, un-issued
+    /// request at that rate would have produced, so p99/p99.9 reflect the real stall instead
+    /// of only the one request that got to run.
+    pub fn record_write_success(
+        &self,
+        writer_id: usize,
+        latency: Duration,
+        retry_count: usize,
+        expected_interval: Option<Duration>,
+    ) {
+        self.record_write_success_for(None, writer_id, latency, retry_count, expected_interval);
+    }
+
+    /// Like [`Self::record_write_success`], also folding the sample into `workload`'s
+    /// `WorkloadCounters` (see `CombinationWorkload`) when `Some`.
+    pub fn record_write_success_for(
+        &self,
+        workload: Option<&str>,
+        writer_id: usize,
+        latency: Duration,
+        retry_count: usize,
+        expected_interval: Option<Duration>,
+    ) {
         self.total_writes_attempted.fetch_add(1, Ordering::Relaxed);
         self.total_writes_succeeded.fetch_add(1, Ordering::Relaxed);
-        self.write_success_latency
-            .lock()
-            .unwrap()
-            .record(latency.as_micros() as u64)
-            .ok();
+        record_with_correction(&mut self.write_success_latency.lock().unwrap(), latency, expected_interval);
         self.retry_counts.lock().unwrap().push(retry_count);
+        self.record_workload(workload, |stats| {
+            stats.writes_attempted += 1;
+            stats.writes_succeeded += 1;
+            record_with_correction(&mut stats.write_hist, latency, expected_interval);
+        });
+
+        let writer_id = writer_id.to_string();
+        let tags = [("writer_id", writer_id.as_str()), ("outcome", "success")];
+        self.fan_out_counter("fusio_manifest.writes", 1, &tags);
+        self.fan_out_timing("fusio_manifest.write_latency", latency, &tags);
     }
 
-    pub fn record_precondition_failure(&self, latency: Duration, retry_count: usize) {
+    pub fn record_precondition_failure(&self, writer_id: usize, latency: Duration, retry_count: usize) {
+        self.record_precondition_failure_for(None, writer_id, latency, retry_count);
+    }
+
+    /// Like [`Self::record_precondition_failure`], tagging `workload`'s `WorkloadCounters`.
+    pub fn record_precondition_failure_for(
+        &self,
+        workload: Option<&str>,
+        writer_id: usize,
+        latency: Duration,
+        retry_count: usize,
+    ) {
         self.total_precondition_failures
             .fetch_add(1, Ordering::Relaxed);
         self.precondition_failure_latency
@@ -118,10 +594,22 @@ impl MetricsCollector {
             .unwrap()
             .record(latency.as_micros() as u64)
             .ok();
+        self.record_workload(workload, |stats| {
+            stats.writes_attempted += 1;
+            stats.precondition_failures += 1;
+        });
 
         if retry_count > 0 {
             self.total_retry_failures.fetch_add(1, Ordering::Relaxed);
         }
+
+        let writer_id = writer_id.to_string();
+        let tags = [
+            ("writer_id", writer_id.as_str()),
+            ("outcome", "precondition_failure"),
+        ];
+        self.fan_out_counter("fusio_manifest.writes", 1, &tags);
+        self.fan_out_timing("fusio_manifest.write_latency", latency, &tags);
     }
 
     pub fn record_max_retries_exceeded(&self) {
@@ -129,20 +617,65 @@ impl MetricsCollector {
             .fetch_add(1, Ordering::Relaxed);
     }
 
-    pub fn record_write_error(&self, _latency: Duration) {
+    pub fn record_write_error(&self, writer_id: usize, latency: Duration) {
+        self.record_write_error_for(None, writer_id, latency);
+    }
+
+    /// Like [`Self::record_write_error`], tagging `workload`'s `WorkloadCounters`.
+    pub fn record_write_error_for(&self, workload: Option<&str>, writer_id: usize, _latency: Duration) {
         self.total_writes_attempted.fetch_add(1, Ordering::Relaxed);
         self.total_write_errors.fetch_add(1, Ordering::Relaxed);
+        self.record_workload(workload, |stats| stats.writes_attempted += 1);
+
+        let writer_id = writer_id.to_string();
+        let tags = [("writer_id", writer_id.as_str()), ("outcome", "error")];
+        self.fan_out_counter("fusio_manifest.writes", 1, &tags);
     }
 
-    pub fn record_read(&self, latency: Duration) {
+    /// Records a read's latency, applying the same coordinated-omission correction as
+    /// `record_write_success` when `expected_interval` is `Some`.
+    pub fn record_read(&self, reader_id: usize, latency: Duration, expected_interval: Option<Duration>) {
+        self.record_read_for(None, reader_id, latency, expected_interval);
+    }
+
+    /// Like [`Self::record_read`], tagging `workload`'s `WorkloadCounters`.
+    pub fn record_read_for(
+        &self,
+        workload: Option<&str>,
+        reader_id: usize,
+        latency: Duration,
+        expected_interval: Option<Duration>,
+    ) {
         self.total_reads.fetch_add(1, Ordering::Relaxed);
-        self.read_latency
+        record_with_correction(&mut self.read_latency.lock().unwrap(), latency, expected_interval);
+        self.record_workload(workload, |stats| stats.reads += 1);
+
+        let reader_id = reader_id.to_string();
+        let tags = [("reader_id", reader_id.as_str())];
+        self.fan_out_counter("fusio_manifest.reads", 1, &tags);
+        self.fan_out_timing("fusio_manifest.read_latency", latency, &tags);
+    }
+
+    /// Records the gap between an open-loop arrival's scheduled dispatch time and the
+    /// moment it actually started running (see `MockClient::run_open_loop`). Growing delay
+    /// here is the open-loop signal for saturation, in place of the throughput ceiling a
+    /// closed-loop run would show instead.
+    pub fn record_queueing_delay(&self, delay: Duration) {
+        self.queueing_delay
             .lock()
             .unwrap()
-            .record(latency.as_micros() as u64)
+            .record(delay.as_micros() as u64)
             .ok();
     }
 
+    /// Records the host CPU core a worker actually ended up pinned to (see
+    /// `MockClient::pin_to_assigned_cpu`), for provenance in the summary. A no-op from the
+    /// worker's perspective if `WorkloadConfig::cpu_affinity` had no matching rule, since
+    /// nothing calls this in that case.
+    pub fn record_worker_cpu(&self, worker_id: usize, cpu_id: usize) {
+        self.worker_cpu.lock().unwrap().push((worker_id, cpu_id));
+    }
+
     pub fn summary(&self) -> MetricsSummary {
         let elapsed = self.start_time.elapsed();
 
@@ -153,10 +686,31 @@ impl MetricsCollector {
         let total_reads = self.total_reads.load(Ordering::Relaxed);
         let total_retry_failures = self.total_retry_failures.load(Ordering::Relaxed);
         let total_max_retries_exceeded = self.total_max_retries_exceeded.load(Ordering::Relaxed);
+        let total_hot_key_overflows = self.total_hot_key_overflows.load(Ordering::Relaxed);
+        let total_hot_key_reroutes = self.total_hot_key_reroutes.load(Ordering::Relaxed);
+        let total_batch_ops_committed = self.total_batch_ops_committed.load(Ordering::Relaxed);
+        let total_batch_commits_succeeded = self.total_batch_commits_succeeded.load(Ordering::Relaxed);
+        let total_batch_precondition_failures =
+            self.total_batch_precondition_failures.load(Ordering::Relaxed);
+        let total_checksum_mismatches = self.total_checksum_mismatches.load(Ordering::Relaxed);
+        let total_multipart_uploads_completed =
+            self.total_multipart_uploads_completed.load(Ordering::Relaxed);
+        let total_multipart_uploads_aborted =
+            self.total_multipart_uploads_aborted.load(Ordering::Relaxed);
+        let total_multipart_uploads_dropped =
+            self.total_multipart_uploads_dropped.load(Ordering::Relaxed);
+        let total_multipart_parts_uploaded =
+            self.total_multipart_parts_uploaded.load(Ordering::Relaxed);
+        let total_dead_letters_buffered = self.dead_letters.lock().unwrap().len();
+        let total_dead_letters_dropped = self.total_dead_letters_dropped.load(Ordering::Relaxed);
+
+        let mut worker_cpu_assignments = self.worker_cpu.lock().unwrap().clone();
+        worker_cpu_assignments.sort_by_key(|(worker_id, _)| *worker_id);
 
         let write_hist = self.write_success_latency.lock().unwrap();
         let precond_hist = self.precondition_failure_latency.lock().unwrap();
         let read_hist = self.read_latency.lock().unwrap();
+        let queueing_delay_hist = self.queueing_delay.lock().unwrap();
 
         let retry_counts = self.retry_counts.lock().unwrap();
         let avg_retries = if !retry_counts.is_empty() {
@@ -184,6 +738,41 @@ impl MetricsCollector {
             0.0
         };
 
+        let mut per_workload: Vec<WorkloadBreakdown> = self
+            .workload_stats
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, stats)| WorkloadBreakdown {
+                workload: name.clone(),
+                write_tps: stats.writes_succeeded as f64 / elapsed.as_secs_f64(),
+                read_tps: stats.reads as f64 / elapsed.as_secs_f64(),
+                write_p50_ms: stats.write_hist.value_at_quantile(0.5) as f64 / 1000.0,
+                write_p99_ms: stats.write_hist.value_at_quantile(0.99) as f64 / 1000.0,
+                precondition_failure_rate: if stats.writes_attempted > 0 {
+                    stats.precondition_failures as f64 / stats.writes_attempted as f64
+                } else {
+                    0.0
+                },
+            })
+            .collect();
+        per_workload.sort_by(|a, b| a.workload.cmp(&b.workload));
+
+        let intervals = self.interval_summaries.lock().unwrap();
+        let best_interval_write_tps = intervals
+            .iter()
+            .map(|i| i.write_tps)
+            .fold(None, |acc: Option<f64>, v| Some(acc.map_or(v, |a| a.max(v))));
+        let worst_interval_write_tps = intervals
+            .iter()
+            .map(|i| i.write_tps)
+            .fold(None, |acc: Option<f64>, v| Some(acc.map_or(v, |a| a.min(v))));
+        let worst_interval_write_p99_ms = intervals
+            .iter()
+            .map(|i| i.write_p99_ms)
+            .fold(None, |acc: Option<f64>, v| Some(acc.map_or(v, |a| a.max(v))));
+        drop(intervals);
+
         MetricsSummary {
             duration: elapsed,
             total_write_attempts: total_attempts,
@@ -205,16 +794,109 @@ impl MetricsCollector {
             read_p50_ms: read_hist.value_at_quantile(0.5) as f64 / 1000.0,
             read_p95_ms: read_hist.value_at_quantile(0.95) as f64 / 1000.0,
             read_p99_ms: read_hist.value_at_quantile(0.99) as f64 / 1000.0,
+            queueing_delay_p50_ms: queueing_delay_hist.value_at_quantile(0.5) as f64 / 1000.0,
+            queueing_delay_p99_ms: queueing_delay_hist.value_at_quantile(0.99) as f64 / 1000.0,
             avg_retry_count: avg_retries,
             total_reads,
             total_retry_failures,
             total_max_retries_exceeded,
             retry_failure_rate,
             retry_success_rate,
+            total_hot_key_overflows,
+            total_hot_key_reroutes,
+            batch_ops_per_sec: total_batch_ops_committed as f64 / elapsed.as_secs_f64(),
+            batch_commits_per_sec: total_batch_commits_succeeded as f64 / elapsed.as_secs_f64(),
+            batch_precondition_failure_rate: if total_batch_commits_succeeded
+                + total_batch_precondition_failures
+                > 0
+            {
+                total_batch_precondition_failures as f64
+                    / (total_batch_commits_succeeded + total_batch_precondition_failures) as f64
+            } else {
+                0.0
+            },
+            total_checksum_mismatches,
+            total_multipart_uploads_completed,
+            total_multipart_uploads_aborted,
+            total_multipart_uploads_dropped,
+            total_multipart_parts_uploaded,
+            total_dead_letters_buffered,
+            total_dead_letters_dropped,
+            worker_cpu_assignments,
+            best_interval_write_tps,
+            worst_interval_write_tps,
+            worst_interval_write_p99_ms,
+            per_workload,
+            profile_artifact: None,
         }
     }
 }
 
+/// Per-named-leg TPS/latency/failure-rate, produced when a `CombinationWorkload` tags its
+/// legs' write/read records with a name. Empty for an ordinary single-`WorkloadConfig` run,
+/// since its clients pass `None` to the `_for` record variants, which then skip the per-leg
+/// counters.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct WorkloadBreakdown {
+    pub workload: String,
+    pub write_tps: f64,
+    pub read_tps: f64,
+    pub write_p50_ms: f64,
+    pub write_p99_ms: f64,
+    pub precondition_failure_rate: f64,
+}
+
+/// A 1-second-window snapshot of live throughput, used for progress bars and optional
+/// external dashboard emission while a workload is still running.
+#[derive(Debug, Clone, Copy)]
+pub struct RollingSnapshot {
+    pub write_tps: f64,
+    pub read_tps: f64,
+    pub write_p50_ms: f64,
+    pub write_p99_ms: f64,
+    pub precondition_failure_rate: f64,
+    /// Precondition-failure rate over the entire run so far, unlike `precondition_failure_rate`
+    /// which only covers the window since the previous sample.
+    pub cumulative_precondition_failure_rate: f64,
+    pub in_flight_retries: u64,
+}
+
+impl RollingSnapshot {
+    /// Formats this snapshot as an InfluxDB/StatsD-style line-protocol sample, suitable for
+    /// forwarding to an external dashboard.
+    pub fn to_line_protocol(&self) -> String {
+        format!(
+            "fusio_manifest_perf write_tps={:.2},read_tps={:.2},write_p50_ms={:.2},write_p99_ms={:.2},precondition_failure_rate={:.4},in_flight_retries={}",
+            self.write_tps,
+            self.read_tps,
+            self.write_p50_ms,
+            self.write_p99_ms,
+            self.precondition_failure_rate,
+            self.in_flight_retries
+        )
+    }
+}
+
+/// A diffed, non-overlapping window of throughput/latency covering the reporting period
+/// ending at `elapsed_since_start`, produced by `MetricsCollector::sample_interval`. Unlike
+/// `RollingSnapshot` (coarse, progress-bar-oriented), this carries full per-window latency
+/// quantiles suitable for a time-series plot.
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub struct IntervalSummary {
+    #[serde(serialize_with = "serialize_duration")]
+    pub elapsed_since_start: Duration,
+    #[serde(serialize_with = "serialize_duration")]
+    pub window_duration: Duration,
+    pub write_tps: f64,
+    pub read_tps: f64,
+    pub precondition_failure_rate: f64,
+    pub write_p50_ms: f64,
+    pub write_p95_ms: f64,
+    pub write_p99_ms: f64,
+    pub read_p50_ms: f64,
+    pub read_p99_ms: f64,
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct MetricsSummary {
     #[serde(serialize_with = "serialize_duration")]
@@ -234,12 +916,54 @@ pub struct MetricsSummary {
     pub read_p50_ms: f64,
     pub read_p95_ms: f64,
     pub read_p99_ms: f64,
+    /// Queueing delay between an open-loop arrival's scheduled and actual dispatch time
+    /// (see `MockClient::run_open_loop`); zero-valued when `ArrivalMode::ClosedLoop` was
+    /// used, since nothing ever queues in that mode.
+    pub queueing_delay_p50_ms: f64,
+    pub queueing_delay_p99_ms: f64,
     pub avg_retry_count: f64,
     pub total_reads: u64,
     pub total_retry_failures: u64,
     pub total_max_retries_exceeded: u64,
     pub retry_failure_rate: f64,
     pub retry_success_rate: f64,
+    pub total_hot_key_overflows: u64,
+    pub total_hot_key_reroutes: u64,
+    /// Staged puts/deletes committed per second across all batched write transactions.
+    pub batch_ops_per_sec: f64,
+    /// Batch `commit()` calls completed per second (distinct from `batch_ops_per_sec` when
+    /// `ops_per_txn > 1`, since each commit covers several ops).
+    pub batch_commits_per_sec: f64,
+    pub batch_precondition_failure_rate: f64,
+    pub total_checksum_mismatches: u64,
+    pub total_multipart_uploads_completed: u64,
+    pub total_multipart_uploads_aborted: u64,
+    pub total_multipart_uploads_dropped: u64,
+    pub total_multipart_parts_uploaded: u64,
+    /// Entries currently sitting in the in-memory dead-letter buffer, not yet drained via
+    /// `WorkloadDriver::drain_dead_letters`/`replay_in_memory_dead_letters`.
+    pub total_dead_letters_buffered: usize,
+    /// Dead letters evicted (drop-oldest) to stay within `dlq_capacity`, lost without ever
+    /// being drained.
+    pub total_dead_letters_dropped: u64,
+    /// `(worker_id, cpu_id)` pairs recording the host CPU core each pinned worker actually
+    /// ran on (see `MockClient::pin_to_assigned_cpu`), sorted by `worker_id`. Only workers
+    /// covered by a `WorkloadConfig::cpu_affinity` rule appear here.
+    pub worker_cpu_assignments: Vec<(usize, usize)>,
+    /// Highest per-window write TPS seen across all `MetricsCollector::sample_interval`
+    /// windows recorded during the run, or `None` if `report_interval_secs` was unset.
+    pub best_interval_write_tps: Option<f64>,
+    /// Lowest per-window write TPS seen across all recorded windows.
+    pub worst_interval_write_tps: Option<f64>,
+    /// Highest per-window write p99 latency seen across all recorded windows.
+    pub worst_interval_write_p99_ms: Option<f64>,
+    /// Per-leg breakdown when this run came from a `CombinationWorkload`, sorted by name;
+    /// empty for an ordinary single-`WorkloadConfig` run.
+    pub per_workload: Vec<WorkloadBreakdown>,
+    /// Set by `WorkloadDriver::run` after `self.metrics.summary()` returns, when
+    /// `WorkloadConfig.profiler` wasn't `ProfilerKind::None`. `summary()` itself never touches
+    /// this field -- it has no way to know which profiler (if any) wrapped the run.
+    pub profile_artifact: Option<crate::perf_test::profiler::ProfileArtifact>,
 }
 
 fn serialize_duration<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
@@ -291,6 +1015,63 @@ impl MetricsSummary {
         println!("p50: {:.2}ms", self.read_p50_ms);
         println!("p95: {:.2}ms", self.read_p95_ms);
         println!("p99: {:.2}ms", self.read_p99_ms);
+        println!("\n--- Open-Loop Queueing Delay ---");
+        println!("p50: {:.2}ms", self.queueing_delay_p50_ms);
+        println!("p99: {:.2}ms", self.queueing_delay_p99_ms);
+        println!("\n--- Hot-Key Isolation ---");
+        println!("Overflow events:       {}", self.total_hot_key_overflows);
+        println!("Reroutes:              {}", self.total_hot_key_reroutes);
+        println!("\n--- Batch Writes ---");
+        println!("Ops committed/sec:     {:.2}", self.batch_ops_per_sec);
+        println!("Commits/sec:           {:.2}", self.batch_commits_per_sec);
+        println!(
+            "Batch precondition failure rate: {:.2}%",
+            self.batch_precondition_failure_rate * 100.0
+        );
+        println!("\n--- Checksum Verification ---");
+        println!("Mismatches detected:   {}", self.total_checksum_mismatches);
+        println!("\n--- Multipart Uploads ---");
+        println!("Completed:             {}", self.total_multipart_uploads_completed);
+        println!("Aborted:               {}", self.total_multipart_uploads_aborted);
+        println!("Dropped (orphaned):    {}", self.total_multipart_uploads_dropped);
+        println!("Parts uploaded:        {}", self.total_multipart_parts_uploaded);
+        println!("\n--- Dead Letters ---");
+        println!("Buffered (undrained):  {}", self.total_dead_letters_buffered);
+        println!("Dropped (capacity):    {}", self.total_dead_letters_dropped);
+        if !self.worker_cpu_assignments.is_empty() {
+            println!("\n--- CPU Affinity ---");
+            for (worker_id, cpu_id) in &self.worker_cpu_assignments {
+                println!("worker {worker_id} -> cpu {cpu_id}");
+            }
+        }
+        if let (Some(best), Some(worst), Some(worst_p99)) = (
+            self.best_interval_write_tps,
+            self.worst_interval_write_tps,
+            self.worst_interval_write_p99_ms,
+        ) {
+            println!("\n--- Interval Sampling (best/worst window) ---");
+            println!("Best window write TPS:       {:.2}", best);
+            println!("Worst window write TPS:      {:.2}", worst);
+            println!("Worst window write p99:      {:.2}ms", worst_p99);
+        }
+        if !self.per_workload.is_empty() {
+            println!("\n--- Per-Workload Breakdown (CombinationWorkload) ---");
+            for leg in &self.per_workload {
+                println!(
+                    "{}: write_tps={:.2} read_tps={:.2} write_p50={:.2}ms write_p99={:.2}ms precond_fail={:.2}%",
+                    leg.workload,
+                    leg.write_tps,
+                    leg.read_tps,
+                    leg.write_p50_ms,
+                    leg.write_p99_ms,
+                    leg.precondition_failure_rate * 100.0
+                );
+            }
+        }
+        if let Some(artifact) = &self.profile_artifact {
+            println!("\n--- Profile ---");
+            println!("{}: {}", artifact.label, artifact.path);
+        }
         println!("======================================\n");
     }
 }
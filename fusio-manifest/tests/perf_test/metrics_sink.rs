@@ -0,0 +1,216 @@
+//! Pluggable fan-out destinations for live counters and timings. `MetricsCollector`'s
+//! `record_*` methods push to every registered `MetricsSink` in addition to the local
+//! cumulative histograms, so a long-running `WorkloadDriver::run()` can be scraped or
+//! aggregated by an existing observability stack instead of only yielding a final
+//! `MetricsSummary` when the run ends.
+
+use std::collections::{HashMap, HashSet};
+use std::net::UdpSocket;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+/// A destination for live counters and timings. `tags` are `(key, value)` pairs such as
+/// `("writer_id", "3")` or `("outcome", "precondition_failure")`.
+pub trait MetricsSink: Send + Sync {
+    fn incr_counter(&self, name: &str, value: u64, tags: &[(&str, &str)]);
+    fn record_timing(&self, name: &str, duration: Duration, tags: &[(&str, &str)]);
+}
+
+/// Sends StatsD/Datadog-style UDP lines: `name:value|c|#tag:val,tag:val` for counters and
+/// `name:value|ms|#tag:val,tag:val` for timings. Fire-and-forget -- a dropped datagram means
+/// one missed sample, never a blocked writer.
+pub struct StatsdSink {
+    socket: UdpSocket,
+}
+
+impl StatsdSink {
+    pub fn new(addr: &str) -> std::io::Result<Self> {
+        let socket = UdpSocket::bind("0.0.0.0:0")?;
+        socket.connect(addr)?;
+        Ok(Self { socket })
+    }
+
+    fn send(&self, line: String) {
+        if let Err(e) = self.socket.send(line.as_bytes()) {
+            tracing::debug!(error = ?e, "failed to send statsd sample");
+        }
+    }
+
+    fn format_tags(tags: &[(&str, &str)]) -> String {
+        if tags.is_empty() {
+            return String::new();
+        }
+        let joined = tags
+            .iter()
+            .map(|(k, v)| format!("{}:{}", k, v))
+            .collect::<Vec<_>>()
+            .join(",");
+        format!("|#{}", joined)
+    }
+}
+
+impl MetricsSink for StatsdSink {
+    fn incr_counter(&self, name: &str, value: u64, tags: &[(&str, &str)]) {
+        self.send(format!("{}:{}|c{}", name, value, Self::format_tags(tags)));
+    }
+
+    fn record_timing(&self, name: &str, duration: Duration, tags: &[(&str, &str)]) {
+        self.send(format!(
+            "{}:{}|ms{}",
+            name,
+            duration.as_millis(),
+            Self::format_tags(tags)
+        ));
+    }
+}
+
+/// In-memory counter/timing registry exposed as Prometheus text-exposition format over a
+/// small local HTTP endpoint, for scraping by an existing Prometheus deployment.
+pub struct PrometheusSink {
+    counters: Arc<Mutex<HashMap<MetricKey, u64>>>,
+    timing_count: Arc<Mutex<HashMap<MetricKey, u64>>>,
+    timing_sum_ms: Arc<Mutex<HashMap<MetricKey, f64>>>,
+    accept_handle: JoinHandle<()>,
+    local_addr: std::net::SocketAddr,
+}
+
+/// A metric's name plus its pre-rendered `{tag="val",...}` label suffix (empty if untagged),
+/// kept separate so the text-exposition renderer never has to re-parse a combined string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct MetricKey {
+    name: String,
+    labels: String,
+}
+
+impl MetricKey {
+    fn new(name: &str, tags: &[(&str, &str)]) -> Self {
+        let labels = if tags.is_empty() {
+            String::new()
+        } else {
+            let joined = tags
+                .iter()
+                .map(|(k, v)| format!("{}=\"{}\"", k, v))
+                .collect::<Vec<_>>()
+                .join(",");
+            format!("{{{}}}", joined)
+        };
+        Self {
+            name: name.to_string(),
+            labels,
+        }
+    }
+}
+
+impl PrometheusSink {
+    /// Binds a listener on `port` (`0` picks an ephemeral port -- see `local_addr`) and
+    /// starts serving `/metrics` in the background.
+    pub async fn start(port: u16) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+        let local_addr = listener.local_addr()?;
+
+        let counters: Arc<Mutex<HashMap<MetricKey, u64>>> = Arc::new(Mutex::new(HashMap::new()));
+        let timing_count: Arc<Mutex<HashMap<MetricKey, u64>>> = Arc::new(Mutex::new(HashMap::new()));
+        let timing_sum_ms: Arc<Mutex<HashMap<MetricKey, f64>>> = Arc::new(Mutex::new(HashMap::new()));
+
+        let serve_counters = counters.clone();
+        let serve_timing_count = timing_count.clone();
+        let serve_timing_sum_ms = timing_sum_ms.clone();
+
+        let accept_handle = tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = match listener.accept().await {
+                    Ok(pair) => pair,
+                    Err(e) => {
+                        tracing::debug!(error = ?e, "prometheus sink accept loop stopping");
+                        break;
+                    }
+                };
+
+                let body = render_prometheus_text(&serve_counters, &serve_timing_count, &serve_timing_sum_ms);
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+
+                // The request line/headers aren't inspected -- this endpoint only ever
+                // serves one thing, regardless of path.
+                let mut discard = [0u8; 1024];
+                let _ = stream.read(&mut discard).await;
+                if let Err(e) = stream.write_all(response.as_bytes()).await {
+                    tracing::debug!(error = ?e, "failed to serve prometheus scrape");
+                }
+            }
+        });
+
+        Ok(Self {
+            counters,
+            timing_count,
+            timing_sum_ms,
+            accept_handle,
+            local_addr,
+        })
+    }
+
+    pub fn local_addr(&self) -> std::net::SocketAddr {
+        self.local_addr
+    }
+
+    /// Stops the background accept loop. Takes `&self` (not `self`) since this sink is
+    /// typically held as an `Arc<dyn MetricsSink>` by `MetricsCollector` as well as by the
+    /// caller that wants to shut it down.
+    pub fn stop(&self) {
+        self.accept_handle.abort();
+    }
+}
+
+fn render_prometheus_text(
+    counters: &Mutex<HashMap<MetricKey, u64>>,
+    timing_count: &Mutex<HashMap<MetricKey, u64>>,
+    timing_sum_ms: &Mutex<HashMap<MetricKey, f64>>,
+) -> String {
+    let mut out = String::new();
+    let mut emitted_types: HashSet<String> = HashSet::new();
+
+    for (key, value) in counters.lock().unwrap().iter() {
+        if emitted_types.insert(key.name.clone()) {
+            out.push_str(&format!("# TYPE {} counter\n", key.name));
+        }
+        out.push_str(&format!("{}{} {}\n", key.name, key.labels, value));
+    }
+
+    let counts = timing_count.lock().unwrap();
+    let sums = timing_sum_ms.lock().unwrap();
+    for (key, count) in counts.iter() {
+        let type_name = format!("{}_milliseconds", key.name);
+        if emitted_types.insert(type_name.clone()) {
+            out.push_str(&format!("# TYPE {} summary\n", type_name));
+        }
+        let sum = sums.get(key).copied().unwrap_or(0.0);
+        out.push_str(&format!("{}_sum{} {}\n", key.name, key.labels, sum));
+        out.push_str(&format!("{}_count{} {}\n", key.name, key.labels, count));
+    }
+
+    out
+}
+
+impl MetricsSink for PrometheusSink {
+    fn incr_counter(&self, name: &str, value: u64, tags: &[(&str, &str)]) {
+        *self
+            .counters
+            .lock()
+            .unwrap()
+            .entry(MetricKey::new(name, tags))
+            .or_insert(0) += value;
+    }
+
+    fn record_timing(&self, name: &str, duration: Duration, tags: &[(&str, &str)]) {
+        let key = MetricKey::new(name, tags);
+        *self.timing_count.lock().unwrap().entry(key.clone()).or_insert(0) += 1;
+        *self.timing_sum_ms.lock().unwrap().entry(key).or_insert(0.0) += duration.as_secs_f64() * 1000.0;
+    }
+}
@@ -0,0 +1,25 @@
+pub mod backend;
+pub mod banking;
+pub mod chaos;
+pub mod checksum;
+pub mod client;
+pub mod combination;
+pub mod consistency;
+pub mod dlq;
+pub mod environment;
+pub mod history;
+pub mod linearizability;
+pub mod metrics;
+pub mod metrics_sink;
+pub mod multipart;
+pub mod overflow;
+pub mod profiler;
+pub mod results_store;
+pub mod s3_setup;
+pub mod spec;
+pub mod telemetry;
+pub mod toxiproxy;
+pub mod tui;
+pub mod utils;
+pub mod visualization;
+pub mod workload;
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use rand::distributions::Alphanumeric;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::perf_test::backend::ManifestBackend;
+use fusio_manifest::types::Error;
+
+/// How a multipart upload is interrupted mid-flight, mirroring S3's own failure modes for
+/// `UploadPart`/`CompleteMultipartUpload`.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum MultipartFault {
+    /// Calls `AbortMultipartUpload` after this many parts have uploaded successfully.
+    AbortAfterPart(usize),
+    /// Sleeps this many milliseconds immediately before `CompleteMultipartUpload`.
+    DelayCompleteMs(u64),
+    /// Drops the session after this many parts without aborting or completing, leaving an
+    /// orphaned upload for [`MultipartRegistry::leaked_uploads`] to catch.
+    DropAfterPart(usize),
+}
+
+/// What became of one `run_multipart_upload` call.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MultipartOutcome {
+    /// `CompleteMultipartUpload` succeeded; `etag` is the assembled multipart ETag.
+    Completed { etag: String },
+    /// `fault` was `AbortAfterPart`: the upload was explicitly aborted after some parts.
+    Aborted,
+    /// `fault` was `DropAfterPart`: the session was abandoned, leaving the upload orphaned
+    /// in the registry.
+    Dropped,
+}
+
+#[derive(Debug, Clone)]
+struct UploadState {
+    key: String,
+    total_parts: usize,
+    parts_completed: usize,
+    completed: bool,
+}
+
+/// One upload that was initiated but never completed or aborted -- S3 keeps billing for
+/// these parts until a lifecycle rule or explicit `AbortMultipartUpload` cleans them up.
+#[derive(Debug, Clone)]
+pub struct LeakedUpload {
+    pub upload_id: String,
+    pub key: String,
+    pub parts_completed: usize,
+    pub total_parts: usize,
+}
+
+/// Tracks every multipart upload this harness has initiated, so a post-run reconciliation
+/// can report uploads that were started but never reached `CompleteMultipartUpload` or
+/// `AbortMultipartUpload`.
+pub struct MultipartRegistry {
+    uploads: Mutex<HashMap<String, UploadState>>,
+    next_id: AtomicUsize,
+}
+
+impl MultipartRegistry {
+    pub fn new() -> Self {
+        Self {
+            uploads: Mutex::new(HashMap::new()),
+            next_id: AtomicUsize::new(0),
+        }
+    }
+
+    /// Registers a new upload and returns its synthetic upload id.
+    fn initiate(&self, key: &str, total_parts: usize) -> String {
+        let upload_id = format!("upload-{}", self.next_id.fetch_add(1, Ordering::Relaxed));
+        self.uploads.lock().unwrap().insert(
+            upload_id.clone(),
+            UploadState {
+                key: key.to_string(),
+                total_parts,
+                parts_completed: 0,
+                completed: false,
+            },
+        );
+        upload_id
+    }
+
+    fn record_part(&self, upload_id: &str) {
+        if let Some(state) = self.uploads.lock().unwrap().get_mut(upload_id) {
+            state.parts_completed += 1;
+        }
+    }
+
+    fn complete(&self, upload_id: &str) {
+        if let Some(state) = self.uploads.lock().unwrap().get_mut(upload_id) {
+            state.completed = true;
+        }
+    }
+
+    /// Removes `upload_id` from tracking, as `AbortMultipartUpload` would.
+    fn abort(&self, upload_id: &str) {
+        self.uploads.lock().unwrap().remove(upload_id);
+    }
+
+    /// Every upload that's neither completed nor aborted: started sessions with no trace
+    /// of how they ended.
+    pub fn leaked_uploads(&self) -> Vec<LeakedUpload> {
+        self.uploads
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, state)| !state.completed)
+            .map(|(upload_id, state)| LeakedUpload {
+                upload_id: upload_id.clone(),
+                key: state.key.clone(),
+                parts_completed: state.parts_completed,
+                total_parts: state.total_parts,
+            })
+            .collect()
+    }
+}
+
+impl Default for MultipartRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn generate_part(size: usize) -> String {
+    StdRng::from_entropy()
+        .sample_iter(&Alphanumeric)
+        .take(size)
+        .map(char::from)
+        .collect()
+}
+
+/// The multipart ETag S3 returns for a completed upload: the hex MD5 of the concatenated
+/// per-part MD5s, suffixed with the part count.
+fn assemble_etag(part_digests: &[String]) -> String {
+    let concatenated = part_digests.join("");
+    format!("{:x}-{}", md5::compute(concatenated.as_bytes()), part_digests.len())
+}
+
+/// Runs one multipart upload against `manifest`: initiates, uploads `num_parts` parts of
+/// `part_size` bytes each concurrently, then completes with the assembled ETag. If `fault`
+/// is set, the upload is interrupted at the point it describes instead of completing
+/// normally.
+pub async fn run_multipart_upload<B: ManifestBackend>(
+    manifest: &B,
+    registry: &MultipartRegistry,
+    key: &str,
+    part_size: usize,
+    num_parts: usize,
+    fault: Option<MultipartFault>,
+) -> Result<MultipartOutcome, Error> {
+    let upload_id = registry.initiate(key, num_parts);
+
+    let part_handles: Vec<_> = (0..num_parts)
+        .map(|_| tokio::spawn(async move { generate_part(part_size) }))
+        .collect();
+
+    let mut parts = Vec::with_capacity(num_parts);
+    for (part_idx, handle) in part_handles.into_iter().enumerate() {
+        let part = handle.await.expect("part upload task panicked");
+        registry.record_part(&upload_id);
+        parts.push(part);
+
+        if let Some(MultipartFault::AbortAfterPart(after)) = fault {
+            if part_idx + 1 == after {
+                registry.abort(&upload_id);
+                return Ok(MultipartOutcome::Aborted);
+            }
+        }
+        if let Some(MultipartFault::DropAfterPart(after)) = fault {
+            if part_idx + 1 == after {
+                return Ok(MultipartOutcome::Dropped);
+            }
+        }
+    }
+
+    if let Some(MultipartFault::DelayCompleteMs(delay_ms)) = fault {
+        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+    }
+
+    let part_digests: Vec<String> = parts.iter().map(|p| format!("{:x}", md5::compute(p.as_bytes()))).collect();
+    let etag = assemble_etag(&part_digests);
+    let assembled_value = parts.join("");
+
+    let mut session = manifest.session_write().await?;
+    session.put(key.to_string(), assembled_value);
+    session.commit().await?;
+
+    registry.complete(&upload_id);
+    Ok(MultipartOutcome::Completed { etag })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::perf_test::backend::InMemoryManifest;
+
+    #[tokio::test]
+    async fn test_completed_upload_is_not_leaked() {
+        let manifest = InMemoryManifest::new();
+        let registry = MultipartRegistry::new();
+
+        let outcome = run_multipart_upload(&manifest, &registry, "k1", 16, 4, None)
+            .await
+            .unwrap();
+
+        assert!(matches!(outcome, MultipartOutcome::Completed { .. }));
+        assert!(registry.leaked_uploads().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dropped_upload_is_leaked_with_partial_parts() {
+        let manifest = InMemoryManifest::new();
+        let registry = MultipartRegistry::new();
+
+        let outcome = run_multipart_upload(
+            &manifest,
+            &registry,
+            "k1",
+            16,
+            4,
+            Some(MultipartFault::DropAfterPart(2)),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(outcome, MultipartOutcome::Dropped);
+        let leaked = registry.leaked_uploads();
+        assert_eq!(leaked.len(), 1);
+        assert_eq!(leaked[0].parts_completed, 2);
+        assert_eq!(leaked[0].total_parts, 4);
+    }
+
+    #[tokio::test]
+    async fn test_aborted_upload_is_not_leaked() {
+        let manifest = InMemoryManifest::new();
+        let registry = MultipartRegistry::new();
+
+        let outcome = run_multipart_upload(
+            &manifest,
+            &registry,
+            "k1",
+            16,
+            4,
+            Some(MultipartFault::AbortAfterPart(2)),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(outcome, MultipartOutcome::Aborted);
+        assert!(registry.leaked_uploads().is_empty());
+    }
+}
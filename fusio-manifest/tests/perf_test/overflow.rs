@@ -0,0 +1,107 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::task::JoinHandle;
+
+/// Per-key GCRA (generic cell rate algorithm) rate limiter used to detect and isolate
+/// "hot" keys under optimistic-concurrency pressure.
+///
+/// Each key tracks a theoretical arrival time (TAT). A request at `now` is within budget
+/// iff `now + burst_tolerance >= TAT`, in which case the TAT advances by
+/// `emission_interval = 1 / per_second_limit`. Otherwise the key is overflowing.
+pub struct GcraLimiter {
+    per_second_limit: f64,
+    burst_tolerance: Duration,
+    ttl: Duration,
+    forced_overflow_keys: HashSet<String>,
+    tat: Mutex<HashMap<String, Instant>>,
+}
+
+impl GcraLimiter {
+    pub fn new(
+        per_second_limit: f64,
+        burst_tolerance: Duration,
+        ttl: Duration,
+        forced_overflow_keys: HashSet<String>,
+    ) -> Self {
+        Self {
+            per_second_limit,
+            burst_tolerance,
+            ttl,
+            forced_overflow_keys,
+            tat: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns `true` if `key` is within budget (and advances its TAT), `false` if it is
+    /// currently overflowing.
+    pub fn check(&self, key: &str) -> bool {
+        if self.forced_overflow_keys.contains(key) {
+            return false;
+        }
+
+        let now = Instant::now();
+        let emission_interval = Duration::from_secs_f64(1.0 / self.per_second_limit);
+
+        let mut tat_map = self.tat.lock().unwrap();
+        let tat = tat_map.get(key).copied().unwrap_or(now);
+
+        if now + self.burst_tolerance >= tat {
+            let new_tat = now.max(tat) + emission_interval;
+            tat_map.insert(key.to_string(), new_tat);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Evicts entries whose TAT has aged out past `ttl`, bounding memory on long runs.
+    pub fn clean_state(&self) {
+        let now = Instant::now();
+        let ttl = self.ttl;
+        let mut tat_map = self.tat.lock().unwrap();
+        tat_map.retain(|_, tat| now.saturating_duration_since(*tat) < ttl);
+    }
+
+    pub fn tracked_key_count(&self) -> usize {
+        self.tat.lock().unwrap().len()
+    }
+
+    /// Spawns a background task that periodically calls `clean_state`.
+    pub fn spawn_cleaner(self: Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                self.clean_state();
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcra_allows_within_budget() {
+        let limiter = GcraLimiter::new(10.0, Duration::from_millis(50), Duration::from_secs(60), HashSet::new());
+        assert!(limiter.check("key_000001"));
+    }
+
+    #[test]
+    fn test_gcra_overflows_hot_key() {
+        let limiter = GcraLimiter::new(1.0, Duration::from_millis(0), Duration::from_secs(60), HashSet::new());
+        assert!(limiter.check("key_000001"));
+        assert!(!limiter.check("key_000001"));
+    }
+
+    #[test]
+    fn test_gcra_forced_overflow() {
+        let mut forced = HashSet::new();
+        forced.insert("key_000001".to_string());
+        let limiter = GcraLimiter::new(1000.0, Duration::from_secs(1), Duration::from_secs(60), forced);
+        assert!(!limiter.check("key_000001"));
+    }
+}
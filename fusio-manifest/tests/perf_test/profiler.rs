@@ -0,0 +1,197 @@
+//! Pluggable profiling hooks around `WorkloadDriver::run`'s active phase (see
+//! `WorkloadConfig.profiler`). `Samply` shells out to an external sampling profiler attached
+//! to this process; `SysMonitor` needs nothing installed -- it samples this process's RSS and
+//! an approximate S3 request count on a timer into a CSV time series. Either way, pathological
+//! p99/failure-rate configs get an artifact a user can open directly instead of re-running
+//! under `perf`/`strace` by hand.
+
+use std::process::{Child, Command};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use csv::Writer;
+
+use crate::perf_test::metrics::MetricsCollector;
+
+/// Which profiler (if any) `WorkloadDriver::run` wraps its active phase with. `None` (the
+/// default) keeps `run()` exactly as before.
+#[derive(Debug, Clone, Copy, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub enum ProfilerKind {
+    #[default]
+    None,
+    /// Attaches `samply record --pid <this process>` for the run's active phase, producing a
+    /// Firefox-Profiler-format trace. Requires `samply` on `PATH`.
+    Samply,
+    /// Samples this process's RSS and `MetricsCollector::approx_s3_request_count` on a timer
+    /// into a CSV time series. No external tool required.
+    SysMonitor,
+}
+
+/// The artifact a `Profiler` produced, once stopped. Surfaced by `MetricsSummary::print_report`
+/// via `WorkloadDriver::run` attaching it to the summary it returns.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ProfileArtifact {
+    pub label: String,
+    pub path: String,
+}
+
+/// Started right before `WorkloadDriver::run` spawns its writers/readers and stopped right
+/// after they join, so neither account-seeding nor teardown pollutes the capture.
+pub trait Profiler: Send {
+    fn start(&mut self);
+    fn stop(self: Box<Self>) -> ProfileArtifact;
+}
+
+/// Builds the `Profiler` for `kind`, or `None` for `ProfilerKind::None`. `output_path` is
+/// reused as the artifact path regardless of which profiler is selected, so a sweep can key
+/// artifacts by `create_config_label` the same way it keys CSV/results-store rows.
+pub fn build_profiler(
+    kind: ProfilerKind,
+    output_path: String,
+    metrics: Arc<MetricsCollector>,
+) -> Option<Box<dyn Profiler>> {
+    match kind {
+        ProfilerKind::None => None,
+        ProfilerKind::Samply => Some(Box::new(SamplyProfiler::new(output_path))),
+        ProfilerKind::SysMonitor => {
+            Some(Box::new(SysMonitorProfiler::new(output_path, Duration::from_secs(1), metrics)))
+        }
+    }
+}
+
+/// Spawns `samply record --pid <this process> --save-only -o <path>` on `start()` and kills
+/// it on `stop()`. If `samply` isn't on `PATH` (or fails to spawn for any other reason), logs
+/// a warning and continues the run unprofiled -- a missing profiler shouldn't fail the whole
+/// workload.
+struct SamplyProfiler {
+    output_path: String,
+    child: Option<Child>,
+}
+
+impl SamplyProfiler {
+    fn new(output_path: String) -> Self {
+        Self { output_path, child: None }
+    }
+}
+
+impl Profiler for SamplyProfiler {
+    fn start(&mut self) {
+        match Command::new("samply")
+            .args([
+                "record",
+                "--pid",
+                &std::process::id().to_string(),
+                "--save-only",
+                "-o",
+                &self.output_path,
+            ])
+            .spawn()
+        {
+            Ok(child) => self.child = Some(child),
+            Err(e) => tracing::warn!(error = %e, "failed to spawn samply, continuing without profiling"),
+        }
+    }
+
+    fn stop(mut self: Box<Self>) -> ProfileArtifact {
+        if let Some(mut child) = self.child.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+        ProfileArtifact { label: "samply trace".to_string(), path: self.output_path }
+    }
+}
+
+/// Samples `/proc/self/status` (`VmRSS`) and `MetricsCollector::approx_s3_request_count` every
+/// `interval` onto a CSV time series at `output_path`. `stop()` signals the sampling task to
+/// exit but doesn't wait on it -- the file is flushed after every row, so whatever was written
+/// before `stop()` is already durable.
+struct SysMonitorProfiler {
+    output_path: String,
+    interval: Duration,
+    metrics: Arc<MetricsCollector>,
+    shutdown: Option<tokio::sync::watch::Sender<bool>>,
+}
+
+impl SysMonitorProfiler {
+    fn new(output_path: String, interval: Duration, metrics: Arc<MetricsCollector>) -> Self {
+        Self { output_path, interval, metrics, shutdown: None }
+    }
+}
+
+impl Profiler for SysMonitorProfiler {
+    fn start(&mut self) {
+        let (shutdown_tx, mut shutdown_rx) = tokio::sync::watch::channel(false);
+        self.shutdown = Some(shutdown_tx);
+
+        let output_path = self.output_path.clone();
+        let interval = self.interval;
+        let metrics = self.metrics.clone();
+
+        tokio::spawn(async move {
+            let file = match std::fs::File::create(&output_path) {
+                Ok(file) => file,
+                Err(e) => {
+                    tracing::warn!(error = %e, path = %output_path, "failed to create sys monitor output");
+                    return;
+                }
+            };
+            let mut wtr = Writer::from_writer(file);
+            if wtr.write_record(["elapsed_secs", "rss_kb", "approx_s3_requests"]).is_err() {
+                return;
+            }
+
+            let start = Instant::now();
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => {}
+                    _ = shutdown_rx.changed() => {}
+                }
+                if *shutdown_rx.borrow() {
+                    break;
+                }
+
+                let row = [
+                    format!("{:.1}", start.elapsed().as_secs_f64()),
+                    read_rss_kb().unwrap_or(0).to_string(),
+                    metrics.approx_s3_request_count().to_string(),
+                ];
+                if wtr.write_record(row).is_err() || wtr.flush().is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    fn stop(mut self: Box<Self>) -> ProfileArtifact {
+        if let Some(tx) = self.shutdown.take() {
+            tx.send_replace(true);
+        }
+        ProfileArtifact { label: "sys monitor time series".to_string(), path: self.output_path }
+    }
+}
+
+fn read_rss_kb() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    status.lines().find_map(|line| {
+        line.strip_prefix("VmRSS:")
+            .and_then(|rest| rest.trim().trim_end_matches(" kB").trim().parse().ok())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn none_profiler_kind_builds_nothing() {
+        let metrics = Arc::new(MetricsCollector::new());
+        assert!(build_profiler(ProfilerKind::None, "ignored".to_string(), metrics).is_none());
+    }
+
+    #[test]
+    fn sys_monitor_profiler_kind_builds_something() {
+        let metrics = Arc::new(MetricsCollector::new());
+        assert!(build_profiler(ProfilerKind::SysMonitor, "ignored".to_string(), metrics).is_some());
+    }
+}
@@ -0,0 +1,439 @@
+//! SQLite-backed results store -- the source of truth for sweep results. Replaces the
+//! `get_best_config_from_csv` linear scan with actual queries: a Pareto frontier over
+//! `(failure_rate, throughput)`, filtering by `ChaosScenario::label()`, and diffing two
+//! sweeps against each other. Flat CSV (`visualization::export_results_csv`) stays around as
+//! one output format for humans/plotting, but this store is what accumulates across runs and
+//! lets sweeps be compared across code versions.
+
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rusqlite::{params, Connection};
+
+use crate::perf_test::{
+    environment::RunEnvironment,
+    metrics::MetricsSummary,
+    utils::{create_config_label, WorkloadConfig},
+};
+
+pub struct ResultsStore {
+    conn: Mutex<Connection>,
+    /// Captured once per `open()` and stamped onto every `record()` in this process, rather
+    /// than re-shelling out to `git`/`rustc`/`hostname` on every call.
+    environment: RunEnvironment,
+}
+
+impl ResultsStore {
+    pub fn open(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        if let Some(parent) = Path::new(path).parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let conn = Connection::open(path)?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS results (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                sweep_prefix TEXT NOT NULL,
+                scenario_label TEXT,
+                config_label TEXT NOT NULL,
+                num_writers INTEGER NOT NULL,
+                num_readers INTEGER NOT NULL,
+                writer_rate REAL NOT NULL,
+                reader_rate REAL NOT NULL,
+                key_overlap_ratio REAL NOT NULL,
+                duration_secs REAL NOT NULL,
+                precondition_failure_rate REAL NOT NULL,
+                write_tps REAL NOT NULL,
+                read_tps REAL NOT NULL,
+                write_p99_ms REAL NOT NULL,
+                config_json TEXT NOT NULL,
+                summary_json TEXT NOT NULL,
+                recorded_at_unix REAL NOT NULL,
+                environment_json TEXT NOT NULL
+             );
+             CREATE INDEX IF NOT EXISTS idx_results_sweep ON results(sweep_prefix);
+             CREATE INDEX IF NOT EXISTS idx_results_scenario ON results(scenario_label);",
+        )?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+            environment: RunEnvironment::capture(),
+        })
+    }
+
+    /// Records one run's config + measured summary under `sweep_prefix`, optionally tagged
+    /// with the `ChaosScenario::label()` it ran under. Every row is stamped with this store's
+    /// `RunEnvironment`, captured once in `open()`.
+    pub fn record(
+        &self,
+        sweep_prefix: &str,
+        scenario_label: Option<&str>,
+        config: &WorkloadConfig,
+        summary: &MetricsSummary,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let config_json = serde_json::to_string(config)?;
+        let summary_json = serde_json::to_string(summary)?;
+        let environment_json = serde_json::to_string(&self.environment)?;
+        let recorded_at_unix = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs_f64();
+
+        self.conn.lock().unwrap().execute(
+            "INSERT INTO results (
+                sweep_prefix, scenario_label, config_label, num_writers, num_readers,
+                writer_rate, reader_rate, key_overlap_ratio, duration_secs,
+                precondition_failure_rate, write_tps, read_tps, write_p99_ms, config_json,
+                summary_json, recorded_at_unix, environment_json
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)",
+            params![
+                sweep_prefix,
+                scenario_label,
+                create_config_label(config),
+                config.num_writers as i64,
+                config.num_readers as i64,
+                config.writer_rate,
+                config.reader_rate,
+                config.key_overlap_ratio,
+                config.duration.as_secs_f64(),
+                summary.precondition_failure_rate,
+                summary.write_tps,
+                summary.read_tps,
+                summary.write_p99_ms,
+                config_json,
+                summary_json,
+                recorded_at_unix,
+                environment_json,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// The Pareto frontier over `(precondition_failure_rate, write_tps + read_tps)` within
+    /// `sweep_prefix`: runs not dominated by another run with both a lower-or-equal failure
+    /// rate and a higher-or-equal combined throughput (and strictly better in at least one).
+    pub fn pareto_frontier(&self, sweep_prefix: &str) -> Result<Vec<ResultRow>, Box<dyn std::error::Error>> {
+        Ok(pareto_frontier(self.query_sweep(sweep_prefix)?))
+    }
+
+    /// All rows for `sweep_prefix` that ran under `scenario_label`.
+    pub fn query_scenario(
+        &self,
+        sweep_prefix: &str,
+        scenario_label: &str,
+    ) -> Result<Vec<ResultRow>, Box<dyn std::error::Error>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(&format!("{} WHERE sweep_prefix = ?1 AND scenario_label = ?2", SELECT_ROW))?;
+        let rows = stmt
+            .query_map(params![sweep_prefix, scenario_label], row_from_sql)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(rows)
+    }
+
+    /// The single best-by-failure-rate config across every sweep ever recorded in this store,
+    /// mirroring the old `get_best_config_from_csv` semantics (which also scanned its whole
+    /// file regardless of sweep).
+    pub fn best_config_overall(&self) -> Result<Option<WorkloadConfig>, Box<dyn std::error::Error>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(&format!(
+            "{} ORDER BY precondition_failure_rate ASC LIMIT 1",
+            SELECT_ROW
+        ))?;
+        let mut rows = stmt.query_map([], row_from_sql)?;
+        match rows.next() {
+            Some(row) => Ok(Some(serde_json::from_str(&row?.config_json)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Compares two sweeps run-by-run (matched by `config_label`): for each config present in
+    /// both, the after-minus-before delta in failure rate, throughput, and write p99 latency.
+    pub fn diff_sweeps(
+        &self,
+        before_prefix: &str,
+        after_prefix: &str,
+    ) -> Result<Vec<SweepDiffEntry>, Box<dyn std::error::Error>> {
+        let before = self.query_sweep(before_prefix)?;
+        let after = self.query_sweep(after_prefix)?;
+
+        let mut diffs: Vec<SweepDiffEntry> = after
+            .iter()
+            .filter_map(|after_row| {
+                before
+                    .iter()
+                    .find(|before_row| before_row.config_label == after_row.config_label)
+                    .map(|before_row| SweepDiffEntry {
+                        config_label: after_row.config_label.clone(),
+                        failure_rate_delta: after_row.precondition_failure_rate - before_row.precondition_failure_rate,
+                        write_tps_before: before_row.write_tps,
+                        write_tps_delta: after_row.write_tps - before_row.write_tps,
+                        read_tps_delta: after_row.read_tps - before_row.read_tps,
+                        write_p99_ms_delta: after_row.write_p99_ms - before_row.write_p99_ms,
+                    })
+            })
+            .collect();
+        diffs.sort_by(|a, b| a.config_label.cmp(&b.config_label));
+        Ok(diffs)
+    }
+
+    /// Like [`Self::diff_sweeps`], but keeps only the configs whose delta crosses
+    /// `thresholds`, so a PR gate can fail on exactly those instead of eyeballing the full
+    /// diff. A config with no regression in any tracked metric is dropped entirely.
+    pub fn detect_regressions(
+        &self,
+        before_prefix: &str,
+        after_prefix: &str,
+        thresholds: &RegressionThresholds,
+    ) -> Result<Vec<SweepDiffEntry>, Box<dyn std::error::Error>> {
+        Ok(self
+            .diff_sweeps(before_prefix, after_prefix)?
+            .into_iter()
+            .filter(|diff| thresholds.is_regression(diff))
+            .collect())
+    }
+
+    /// Exports every row for `sweep_prefix` as a JSON array, suitable for a dashboard to
+    /// consume directly.
+    pub fn export_json(&self, sweep_prefix: &str, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let rows = self.query_sweep(sweep_prefix)?;
+        std::fs::write(filename, serde_json::to_string_pretty(&rows)?)?;
+        Ok(())
+    }
+
+    fn query_sweep(&self, sweep_prefix: &str) -> Result<Vec<ResultRow>, Box<dyn std::error::Error>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(&format!("{} WHERE sweep_prefix = ?1", SELECT_ROW))?;
+        let rows = stmt
+            .query_map(params![sweep_prefix], row_from_sql)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(rows)
+    }
+}
+
+const SELECT_ROW: &str = "SELECT id, sweep_prefix, scenario_label, config_label, num_writers, \
+    num_readers, writer_rate, reader_rate, key_overlap_ratio, duration_secs, \
+    precondition_failure_rate, write_tps, read_tps, write_p99_ms, config_json, summary_json, \
+    recorded_at_unix, environment_json \
+    FROM results";
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ResultRow {
+    pub id: i64,
+    pub sweep_prefix: String,
+    pub scenario_label: Option<String>,
+    pub config_label: String,
+    pub num_writers: i64,
+    pub num_readers: i64,
+    pub writer_rate: f64,
+    pub reader_rate: f64,
+    pub key_overlap_ratio: f64,
+    pub duration_secs: f64,
+    pub precondition_failure_rate: f64,
+    pub write_tps: f64,
+    pub read_tps: f64,
+    pub write_p99_ms: f64,
+    pub config_json: String,
+    pub summary_json: String,
+    pub recorded_at_unix: f64,
+    pub environment_json: String,
+}
+
+/// The after-minus-before change in a config's tracked metrics between two sweeps, from
+/// [`ResultsStore::diff_sweeps`] / [`ResultsStore::detect_regressions`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SweepDiffEntry {
+    pub config_label: String,
+    pub failure_rate_delta: f64,
+    /// `write_tps` in the before sweep, kept alongside the delta so
+    /// [`RegressionThresholds::write_tps_drop_ratio`] can be expressed relative to it rather
+    /// than as a fixed absolute TPS.
+    pub write_tps_before: f64,
+    pub write_tps_delta: f64,
+    pub read_tps_delta: f64,
+    pub write_p99_ms_delta: f64,
+}
+
+/// Thresholds a [`SweepDiffEntry`] must cross in at least one direction to count as a
+/// regression in [`ResultsStore::detect_regressions`].
+#[derive(Debug, Clone)]
+pub struct RegressionThresholds {
+    /// Flag if `write_tps` drops by more than this fraction of the before value (e.g. `0.1`
+    /// for "more than a 10% drop").
+    pub write_tps_drop_ratio: f64,
+    /// Flag if `write_p99_ms` rises by more than this many milliseconds.
+    pub write_p99_ms_increase: f64,
+    /// Flag if `precondition_failure_rate` rises by more than this many percentage points
+    /// (e.g. `0.05` for "more than 5pp worse").
+    pub precondition_failure_rate_increase: f64,
+}
+
+impl Default for RegressionThresholds {
+    /// A 10% TPS drop, a 50ms p99 rise, or a 5-percentage-point failure-rate rise all count
+    /// as a regression.
+    fn default() -> Self {
+        Self {
+            write_tps_drop_ratio: 0.1,
+            write_p99_ms_increase: 50.0,
+            precondition_failure_rate_increase: 0.05,
+        }
+    }
+}
+
+impl RegressionThresholds {
+    fn is_regression(&self, diff: &SweepDiffEntry) -> bool {
+        let write_tps_drop_ratio = if diff.write_tps_before > 0.0 {
+            -diff.write_tps_delta / diff.write_tps_before
+        } else {
+            0.0
+        };
+
+        write_tps_drop_ratio > self.write_tps_drop_ratio
+            || diff.write_p99_ms_delta > self.write_p99_ms_increase
+            || diff.failure_rate_delta > self.precondition_failure_rate_increase
+    }
+}
+
+fn row_from_sql(row: &rusqlite::Row) -> rusqlite::Result<ResultRow> {
+    Ok(ResultRow {
+        id: row.get(0)?,
+        sweep_prefix: row.get(1)?,
+        scenario_label: row.get(2)?,
+        config_label: row.get(3)?,
+        num_writers: row.get(4)?,
+        num_readers: row.get(5)?,
+        writer_rate: row.get(6)?,
+        reader_rate: row.get(7)?,
+        key_overlap_ratio: row.get(8)?,
+        duration_secs: row.get(9)?,
+        precondition_failure_rate: row.get(10)?,
+        write_tps: row.get(11)?,
+        read_tps: row.get(12)?,
+        write_p99_ms: row.get(13)?,
+        config_json: row.get(14)?,
+        summary_json: row.get(15)?,
+        recorded_at_unix: row.get(16)?,
+        environment_json: row.get(17)?,
+    })
+}
+
+fn pareto_frontier(rows: Vec<ResultRow>) -> Vec<ResultRow> {
+    let throughput = |r: &ResultRow| r.write_tps + r.read_tps;
+
+    rows.iter()
+        .filter(|candidate| {
+            !rows.iter().any(|other| {
+                other.id != candidate.id
+                    && other.precondition_failure_rate <= candidate.precondition_failure_rate
+                    && throughput(other) >= throughput(candidate)
+                    && (other.precondition_failure_rate < candidate.precondition_failure_rate
+                        || throughput(other) > throughput(candidate))
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(id: i64, failure_rate: f64, write_tps: f64, read_tps: f64) -> ResultRow {
+        ResultRow {
+            id,
+            sweep_prefix: "sweep".to_string(),
+            scenario_label: None,
+            config_label: format!("cfg-{}", id),
+            num_writers: 1,
+            num_readers: 1,
+            writer_rate: 1.0,
+            reader_rate: 1.0,
+            key_overlap_ratio: 0.0,
+            duration_secs: 60.0,
+            precondition_failure_rate: failure_rate,
+            write_tps,
+            read_tps,
+            write_p99_ms: 10.0,
+            config_json: "{}".to_string(),
+            summary_json: "{}".to_string(),
+            recorded_at_unix: 0.0,
+            environment_json: "{}".to_string(),
+        }
+    }
+
+    #[test]
+    fn dominated_row_is_excluded_from_frontier() {
+        let rows = vec![
+            row(1, 0.1, 100.0, 50.0),
+            row(2, 0.2, 80.0, 40.0),  // dominated by row 1 on both axes
+            row(3, 0.05, 60.0, 30.0), // lower failure rate, lower throughput: on the frontier
+        ];
+        let ids: Vec<i64> = pareto_frontier(rows).iter().map(|r| r.id).collect();
+        assert!(ids.contains(&1));
+        assert!(ids.contains(&3));
+        assert!(!ids.contains(&2));
+    }
+
+    #[test]
+    fn diff_matches_rows_by_config_label() {
+        let store = ResultsStore::open(":memory:").expect("failed to open in-memory results store");
+        // config_label differs by id in this helper, so relabel by hand: one config run by
+        // both sweeps, and one run only by the after sweep.
+        let runs = vec![
+            ("before", "cfg-shared", row(1, 0.2, 50.0, 20.0)),
+            ("after", "cfg-shared", row(2, 0.1, 60.0, 25.0)),
+            ("after", "cfg-after-only", row(3, 0.0, 90.0, 30.0)),
+        ];
+        for (prefix, label, r) in runs {
+            store
+                .conn
+                .lock()
+                .unwrap()
+                .execute(
+                    "INSERT INTO results (
+                        sweep_prefix, config_label, num_writers, num_readers, writer_rate,
+                        reader_rate, key_overlap_ratio, duration_secs, precondition_failure_rate,
+                        write_tps, read_tps, write_p99_ms, config_json, summary_json,
+                        recorded_at_unix, environment_json
+                     ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
+                    params![
+                        prefix, label, r.num_writers, r.num_readers, r.writer_rate, r.reader_rate,
+                        r.key_overlap_ratio, r.duration_secs, r.precondition_failure_rate, r.write_tps,
+                        r.read_tps, r.write_p99_ms, r.config_json, r.summary_json, r.recorded_at_unix,
+                        r.environment_json
+                    ],
+                )
+                .expect("failed to insert test row");
+        }
+
+        let diffs = store.diff_sweeps("before", "after").expect("diff_sweeps failed");
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].config_label, "cfg-shared");
+        assert!(diffs[0].write_tps_delta + diffs[0].read_tps_delta > 0.0);
+    }
+
+    #[test]
+    fn regression_flags_tps_drop_beyond_threshold() {
+        let thresholds = RegressionThresholds::default();
+        let diff = SweepDiffEntry {
+            config_label: "cfg".to_string(),
+            failure_rate_delta: 0.0,
+            write_tps_before: 100.0,
+            write_tps_delta: -15.0, // 15% drop, over the default 10% threshold
+            read_tps_delta: 0.0,
+            write_p99_ms_delta: 0.0,
+        };
+        assert!(thresholds.is_regression(&diff));
+    }
+
+    #[test]
+    fn regression_ignores_small_tps_drop_and_latency_improvement() {
+        let thresholds = RegressionThresholds::default();
+        let diff = SweepDiffEntry {
+            config_label: "cfg".to_string(),
+            failure_rate_delta: -0.01,
+            write_tps_before: 100.0,
+            write_tps_delta: -2.0, // 2% drop, under threshold
+            read_tps_delta: 1.0,
+            write_p99_ms_delta: -5.0,
+        };
+        assert!(!thresholds.is_regression(&diff));
+    }
+}
@@ -0,0 +1,76 @@
+//! Real-S3 manifest construction shared between the hand-written `#[ignore]`d scenarios in
+//! `performance_test.rs` and the `fusio-manifest-bench` binary that drives `WorkloadFile`
+//! specs (see [`crate::perf_test::spec`]) -- pulled out of `performance_test.rs` so the
+//! binary doesn't need its own copy of the AWS credential/endpoint wiring.
+
+use std::{env, sync::Arc};
+
+use fusio::executor::tokio::TokioExecutor;
+use fusio_manifest::{context::ManifestContext, s3::{self, S3Manifest}};
+
+use crate::perf_test::utils::{create_test_prefix, load_aws_credentials};
+
+pub fn create_real_s3_manifest(
+    test_name: &str,
+) -> Result<S3Manifest<String, String, TokioExecutor>, Box<dyn std::error::Error>> {
+    let prefix = create_test_prefix(test_name);
+    create_real_s3_manifest_with_prefix(&prefix)
+}
+
+/// The `host:port` the S3 endpoint currently resolves to (either `AWS_ENDPOINT_URL` or the
+/// regional AWS S3 endpoint), for pointing a [`crate::perf_test::toxiproxy::ToxicProxy`]
+/// upstream at.
+pub fn s3_upstream_addr() -> Result<String, Box<dyn std::error::Error>> {
+    let url = match env::var("AWS_ENDPOINT_URL") {
+        Ok(ep) => ep,
+        Err(_) => format!("https://s3.{}.amazonaws.com", load_aws_credentials()?.region),
+    };
+    let without_scheme = url.splitn(2, "://").last().unwrap_or(&url);
+    let host = without_scheme.split('/').next().unwrap_or(without_scheme);
+    Ok(if host.contains(':') {
+        host.to_string()
+    } else if url.starts_with("https://") {
+        format!("{}:443", host)
+    } else {
+        format!("{}:80", host)
+    })
+}
+
+pub fn create_real_s3_manifest_with_prefix(
+    prefix: &str,
+) -> Result<S3Manifest<String, String, TokioExecutor>, Box<dyn std::error::Error>> {
+    create_real_s3_manifest_with_endpoint_override(prefix, None)
+}
+
+/// Like [`create_real_s3_manifest_with_prefix`], but when `endpoint_override` is set (e.g. a
+/// [`crate::perf_test::toxiproxy::ToxicProxy`] local URL), it takes precedence over
+/// `AWS_ENDPOINT_URL` so chaos scenarios can route traffic through the proxy.
+pub fn create_real_s3_manifest_with_endpoint_override(
+    prefix: &str,
+    endpoint_override: Option<String>,
+) -> Result<S3Manifest<String, String, TokioExecutor>, Box<dyn std::error::Error>> {
+    let bucket = env::var("FUSIO_MANIFEST_BUCKET")
+        .unwrap_or_else(|_| "liguoso-tonbo-s3".to_string());
+
+    let creds = load_aws_credentials()?;
+    let endpoint = endpoint_override.or_else(|| env::var("AWS_ENDPOINT_URL").ok());
+
+    let mut builder = s3::Builder::new(&bucket)
+        .prefix(prefix)
+        .region(creds.region)
+        .sign_payload(true)
+        .credential(fusio::impls::remotes::aws::credential::AwsCredential {
+            key_id: creds.access_key_id,
+            secret_key: creds.secret_access_key,
+            token: creds.session_token,
+        });
+
+    if let Some(ep) = endpoint {
+        builder = builder.endpoint(ep);
+    }
+
+    let config = builder.build();
+    let context = Arc::new(ManifestContext::new(TokioExecutor::default()));
+
+    Ok(config.with_context(context).into())
+}
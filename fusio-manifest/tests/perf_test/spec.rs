@@ -0,0 +1,85 @@
+//! Declarative workload-spec files (JSON or TOML), so a new benchmark scenario can be added
+//! as a config file instead of a new hand-written `#[ignore]`d test in `performance_test.rs`.
+//! Loaded and driven by the `fusio-manifest-bench` binary.
+
+use std::path::Path;
+
+use crate::perf_test::chaos::{create_chaos_scenarios, ChaosScenario};
+use crate::perf_test::utils::{generate_all_configs, WorkloadConfig};
+
+/// One workload-spec file. `workload` maps directly onto `WorkloadConfig` -- any field the
+/// file omits falls back to `WorkloadConfig::default()` via its `#[serde(default)]` --
+/// and `chaos`/`sweep` optionally expand it into the same config vectors
+/// `create_chaos_scenarios`/`generate_all_configs` already produce for
+/// `test_chaos_sweep`/`test_comprehensive_sweep`, rather than introducing a second way to
+/// describe them.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct WorkloadFile {
+    #[serde(default)]
+    pub workload: WorkloadConfig,
+    /// When present with `enabled` (the default), ignores `workload` entirely and expands to
+    /// `generate_all_configs()` instead -- mirrors `test_comprehensive_sweep`.
+    pub sweep: Option<SweepSpec>,
+    /// When present with `enabled` (the default), expands to one run per
+    /// `create_chaos_scenarios()` entry, each a clone of `workload` -- mirrors `test_chaos_sweep`.
+    pub chaos: Option<ChaosSpec>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SweepSpec {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ChaosSpec {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// What a loaded `WorkloadFile` expands to, once its `sweep`/`chaos` sections (if any) have
+/// been resolved against the config vectors they reference.
+pub enum ExpandedWorkload {
+    /// A single run of `workload` as given.
+    Single(WorkloadConfig),
+    /// `generate_all_configs()`, run independently of each other.
+    Sweep(Vec<WorkloadConfig>),
+    /// `create_chaos_scenarios()`, each paired with a clone of `workload` to run under it.
+    Chaos(Vec<(ChaosScenario, WorkloadConfig)>),
+}
+
+impl WorkloadFile {
+    /// Loads a spec file, dispatching on its extension: `.toml` for TOML, anything else
+    /// (including `.json`) for JSON.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read workload spec {}: {}", path.display(), e))?;
+
+        if path.extension().and_then(|ext| ext.to_str()) == Some("toml") {
+            Ok(toml::from_str(&text)?)
+        } else {
+            Ok(serde_json::from_str(&text)?)
+        }
+    }
+
+    /// Resolves `sweep`/`chaos` (if set) against the config vectors they reference. `sweep`
+    /// takes precedence over `chaos` if both are somehow set, since it replaces `workload`
+    /// entirely rather than building on top of it.
+    pub fn expand(&self) -> ExpandedWorkload {
+        if self.sweep.as_ref().is_some_and(|s| s.enabled) {
+            return ExpandedWorkload::Sweep(generate_all_configs());
+        }
+        if self.chaos.as_ref().is_some_and(|c| c.enabled) {
+            let runs = create_chaos_scenarios()
+                .into_iter()
+                .map(|scenario| (scenario, self.workload.clone()))
+                .collect();
+            return ExpandedWorkload::Chaos(runs);
+        }
+        ExpandedWorkload::Single(self.workload.clone())
+    }
+}
@@ -0,0 +1,126 @@
+use std::env;
+use std::time::Duration;
+
+use opentelemetry::{global, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{metrics::SdkMeterProvider, runtime, trace::TracerProvider};
+use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+
+use crate::perf_test::metrics::MetricsCollector;
+
+/// Env var carrying the OTLP collector endpoint (e.g. `http://localhost:4317`).
+/// Equivalent to a `--otlp-endpoint` flag for the non-CLI test harness.
+const OTLP_ENDPOINT_ENV: &str = "FUSIO_MANIFEST_OTLP_ENDPOINT";
+const GAUGE_PUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+pub struct TelemetryGuard {
+    tracer_provider: Option<TracerProvider>,
+    meter_provider: Option<SdkMeterProvider>,
+    gauge_task: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl TelemetryGuard {
+    /// Flushes and shuts down the OTLP pipelines. No-op if telemetry was never enabled.
+    pub async fn shutdown(mut self) {
+        if let Some(task) = self.gauge_task.take() {
+            task.abort();
+        }
+        if let Some(provider) = self.meter_provider.take() {
+            let _ = provider.shutdown();
+        }
+        if let Some(provider) = self.tracer_provider.take() {
+            let _ = provider.shutdown();
+        }
+        global::shutdown_tracer_provider();
+    }
+}
+
+/// Initializes tracing. If `FUSIO_MANIFEST_OTLP_ENDPOINT` is set, spans and the aggregated
+/// TPS/latency gauges from `metrics` are exported via OTLP (Jaeger/Tempo compatible);
+/// otherwise this falls back to the plain fmt subscriber used by the rest of the harness.
+pub fn init_telemetry(metrics: Option<&std::sync::Arc<MetricsCollector>>) -> TelemetryGuard {
+    let endpoint = env::var(OTLP_ENDPOINT_ENV).ok();
+
+    let Some(endpoint) = endpoint else {
+        let _ = fmt()
+            .with_env_filter(
+                EnvFilter::try_from_default_env()
+                    .unwrap_or_else(|_| EnvFilter::new("fusio_manifest=debug,performance_test=info")),
+            )
+            .with_target(true)
+            .with_line_number(true)
+            .try_init();
+
+        return TelemetryGuard {
+            tracer_provider: None,
+            meter_provider: None,
+            gauge_task: None,
+        };
+    };
+
+    let tracer_provider = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(&endpoint),
+        )
+        .install_batch(runtime::Tokio)
+        .expect("failed to install OTLP trace pipeline")
+        .into();
+    let tracer = global::tracer("fusio-manifest-perf");
+
+    let meter_provider = opentelemetry_otlp::new_pipeline()
+        .metrics(runtime::Tokio)
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(&endpoint),
+        )
+        .build()
+        .expect("failed to install OTLP metrics pipeline");
+    global::set_meter_provider(meter_provider.clone());
+
+    let otlp_layer = tracing_opentelemetry::layer().with_tracer(tracer);
+
+    let _ = tracing_subscriber::registry()
+        .with(
+            EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| EnvFilter::new("fusio_manifest=debug,performance_test=info")),
+        )
+        .with(fmt::layer().with_target(true).with_line_number(true))
+        .with(otlp_layer)
+        .try_init();
+
+    let gauge_task = metrics.map(|metrics| spawn_gauge_pusher(metrics.clone()));
+
+    tracing::info!(otlp_endpoint = %endpoint, "OTLP telemetry enabled");
+
+    TelemetryGuard {
+        tracer_provider: Some(tracer_provider),
+        meter_provider: Some(meter_provider),
+        gauge_task,
+    }
+}
+
+/// Periodically pushes the aggregated TPS and p50/p95/p99 gauges from `MetricsCollector`
+/// so a live collector dashboard reflects contention and retry storms during the run.
+fn spawn_gauge_pusher(metrics: std::sync::Arc<MetricsCollector>) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let meter = global::meter("fusio-manifest-perf");
+        let write_tps_gauge = meter.f64_gauge("fusio_manifest.write_tps").init();
+        let write_p50_gauge = meter.f64_gauge("fusio_manifest.write_latency_p50_ms").init();
+        let write_p95_gauge = meter.f64_gauge("fusio_manifest.write_latency_p95_ms").init();
+        let write_p99_gauge = meter.f64_gauge("fusio_manifest.write_latency_p99_ms").init();
+
+        let mut ticker = tokio::time::interval(GAUGE_PUSH_INTERVAL);
+        loop {
+            ticker.tick().await;
+            let summary = metrics.summary();
+            write_tps_gauge.record(summary.write_tps, &[KeyValue::new("source", "perf_test")]);
+            write_p50_gauge.record(summary.write_p50_ms, &[]);
+            write_p95_gauge.record(summary.write_p95_ms, &[]);
+            write_p99_gauge.record(summary.write_p99_ms, &[]);
+        }
+    })
+}
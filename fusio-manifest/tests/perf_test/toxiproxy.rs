@@ -0,0 +1,169 @@
+//! A minimal Toxiproxy-style TCP proxy for exercising real network impairment, rather than
+//! the application-level `tokio::time::sleep` stubs in [`crate::perf_test::chaos`]. Stands up
+//! a local listener that forwards bytes to `upstream`, injecting configurable toxics on the
+//! way. Point the client under test (e.g. `s3::Builder::endpoint`) at [`ToxicProxy::local_url`]
+//! instead of the real endpoint.
+//!
+//! `server_error` works by answering the client directly without ever dialing upstream, so it
+//! only makes sense against a plain-HTTP endpoint (a local MinIO/S3-compatible server, the same
+//! kind already pointed to via `AWS_ENDPOINT_URL` for local testing) — it cannot forge a
+//! response inside a TLS session it isn't terminating. Latency, bandwidth capping, and
+//! mid-transfer resets are pure byte-level toxics and work as a transparent passthrough even
+//! over TLS.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+#[derive(Debug, Clone, Default)]
+pub struct ProxyToxics {
+    pub latency_ms: u64,
+    /// Chance (0-100) of severing the response mid-transfer, one roll per chunk relayed.
+    pub drop_pct: u8,
+    pub bandwidth_kbps: Option<u64>,
+    /// `(status, rate)`: with probability `rate` (0.0-1.0), answer the client with a
+    /// synthetic HTTP error instead of proxying to upstream at all.
+    pub server_error: Option<(u16, f64)>,
+}
+
+pub struct ToxicProxy {
+    local_addr: std::net::SocketAddr,
+    accept_handle: JoinHandle<()>,
+    running: Arc<AtomicBool>,
+}
+
+impl ToxicProxy {
+    pub async fn start(upstream_addr: String, toxics: ProxyToxics) -> std::io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let local_addr = listener.local_addr()?;
+        let running = Arc::new(AtomicBool::new(true));
+
+        let accept_handle = tokio::spawn(async move {
+            loop {
+                let (client, _) = match listener.accept().await {
+                    Ok(pair) => pair,
+                    Err(e) => {
+                        tracing::debug!(error = ?e, "toxic proxy accept loop stopping");
+                        break;
+                    }
+                };
+
+                let upstream_addr = upstream_addr.clone();
+                let toxics = toxics.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = handle_connection(client, &upstream_addr, &toxics).await {
+                        tracing::debug!(error = ?e, "toxic proxy connection ended");
+                    }
+                });
+            }
+        });
+
+        Ok(Self {
+            local_addr,
+            accept_handle,
+            running,
+        })
+    }
+
+    /// The URL the harness's HTTP client should be pointed at instead of the real endpoint.
+    pub fn local_url(&self) -> String {
+        format!("http://{}", self.local_addr)
+    }
+
+    pub async fn stop(self) {
+        self.running.store(false, Ordering::SeqCst);
+        self.accept_handle.abort();
+    }
+}
+
+async fn handle_connection(
+    mut client: TcpStream,
+    upstream_addr: &str,
+    toxics: &ProxyToxics,
+) -> std::io::Result<()> {
+    let mut rng = StdRng::from_entropy();
+
+    if let Some((status, rate)) = toxics.server_error {
+        if rng.gen::<f64>() < rate {
+            let body = format!("chaos-injected {} response", status);
+            let response = format!(
+                "HTTP/1.1 {} Chaos Injected\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            client.write_all(response.as_bytes()).await?;
+            return Ok(());
+        }
+    }
+
+    let mut upstream = TcpStream::connect(upstream_addr).await?;
+    let (mut client_read, mut client_write) = client.split();
+    let (mut upstream_read, mut upstream_write) = upstream.split();
+
+    let latency = Duration::from_millis(toxics.latency_ms);
+
+    let request_leg = relay(&mut client_read, &mut upstream_write, latency, toxics.bandwidth_kbps, 0);
+    let response_leg = relay(
+        &mut upstream_read,
+        &mut client_write,
+        latency,
+        toxics.bandwidth_kbps,
+        toxics.drop_pct,
+    );
+
+    tokio::try_join!(request_leg, response_leg)?;
+    Ok(())
+}
+
+/// Copies bytes from `reader` to `writer`, applying per-chunk latency, a bandwidth cap
+/// (byte-rate limiting via sleep), and a `drop_pct` chance of severing the connection
+/// mid-transfer (simulating a connection reset) on each chunk relayed.
+async fn relay<R, W>(
+    reader: &mut R,
+    writer: &mut W,
+    latency: Duration,
+    bandwidth_kbps: Option<u64>,
+    drop_pct: u8,
+) -> std::io::Result<()>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut rng = StdRng::from_entropy();
+    let mut buf = [0u8; 8192];
+
+    loop {
+        let n = reader.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+
+        if drop_pct > 0 && rng.gen_range(0..100) < drop_pct {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::ConnectionReset,
+                "chaos-injected mid-transfer reset",
+            ));
+        }
+
+        if !latency.is_zero() {
+            tokio::time::sleep(latency).await;
+        }
+
+        if let Some(kbps) = bandwidth_kbps {
+            let bytes_per_sec = (kbps * 1024) / 8;
+            if bytes_per_sec > 0 {
+                tokio::time::sleep(Duration::from_secs_f64(n as f64 / bytes_per_sec as f64)).await;
+            }
+        }
+
+        writer.write_all(&buf[..n]).await?;
+    }
+
+    writer.shutdown().await
+}
@@ -0,0 +1,94 @@
+//! Full-screen live dashboard for `WorkloadConfig.live_ui`, an alternative to the one-line
+//! indicatif bar (`WorkloadConfig.progress`) for a single foreground run where a sweep's
+//! parallel batches would otherwise fight over the terminal. Renders write/read TPS, rolling
+//! write-latency p50/p99, cumulative precondition-failure rate, and elapsed/remaining time,
+//! sampled from `MetricsCollector` on a timer via `MetricsCollector::sample_rolling_window`.
+
+use std::io::stdout;
+use std::time::Duration;
+
+use crossterm::{
+    cursor,
+    execute,
+    style::{Print, ResetColor, SetForegroundColor, Color},
+    terminal::{self, Clear, ClearType},
+};
+
+use crate::perf_test::metrics::MetricsCollector;
+
+/// Spawns a task that redraws the dashboard once per `tick` until `duration` elapses or
+/// `shutdown_rx` fires, then restores the terminal. The caller is responsible for not also
+/// enabling `WorkloadConfig.progress` at the same time, since both drive the terminal cursor.
+pub fn spawn_dashboard(
+    metrics: std::sync::Arc<MetricsCollector>,
+    duration: Duration,
+    mut shutdown_rx: tokio::sync::watch::Receiver<bool>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        if terminal::enable_raw_mode().is_err() {
+            tracing::warn!("live_ui requested but terminal doesn't support raw mode, skipping dashboard");
+            return;
+        }
+
+        let mut out = stdout();
+        execute!(out, terminal::EnterAlternateScreen, cursor::Hide).ok();
+
+        let tick = Duration::from_millis(500);
+        let mut ticker = tokio::time::interval(tick);
+        let start = tokio::time::Instant::now();
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                _ = shutdown_rx.changed() => {}
+            }
+            if *shutdown_rx.borrow() {
+                break;
+            }
+
+            let elapsed = start.elapsed();
+            let remaining = duration.saturating_sub(elapsed);
+            let snapshot = metrics.sample_rolling_window();
+            render(&mut out, elapsed, remaining, &snapshot);
+
+            if elapsed >= duration {
+                break;
+            }
+        }
+
+        execute!(out, cursor::Show, terminal::LeaveAlternateScreen).ok();
+        terminal::disable_raw_mode().ok();
+    })
+}
+
+fn render(
+    out: &mut std::io::Stdout,
+    elapsed: Duration,
+    remaining: Duration,
+    snapshot: &crate::perf_test::metrics::RollingSnapshot,
+) {
+    execute!(
+        out,
+        cursor::MoveTo(0, 0),
+        Clear(ClearType::All),
+        SetForegroundColor(Color::Cyan),
+        Print("=== fusio-manifest live workload dashboard ===\r\n"),
+        ResetColor,
+        Print(format!(
+            "elapsed: {}s  remaining: {}s\r\n\r\n",
+            elapsed.as_secs(),
+            remaining.as_secs()
+        )),
+        Print(format!("write TPS:            {:.1}\r\n", snapshot.write_tps)),
+        Print(format!("read TPS:             {:.1}\r\n", snapshot.read_tps)),
+        Print(format!("write p50 latency:    {:.2}ms\r\n", snapshot.write_p50_ms)),
+        Print(format!("write p99 latency:    {:.2}ms\r\n", snapshot.write_p99_ms)),
+        Print(format!(
+            "precond failure rate: {:.2}% (window), {:.2}% (cumulative)\r\n",
+            snapshot.precondition_failure_rate * 100.0,
+            snapshot.cumulative_precondition_failure_rate * 100.0
+        )),
+        Print(format!("in-flight retries:    {}\r\n", snapshot.in_flight_retries)),
+    )
+    .ok();
+}
@@ -3,12 +3,17 @@ use std::path::PathBuf;
 use std::env;
 use std::sync::{Arc, Mutex, atomic::{AtomicUsize, Ordering}};
 use ini::Ini;
-use rand::{seq::SliceRandom, rngs::StdRng, SeedableRng};
+use rand::{seq::SliceRandom, rngs::StdRng, Rng, SeedableRng};
 
-#[derive(Debug, Clone)]
+/// `#[serde(default)]` lets a `WorkloadFile` (see `crate::perf_test::spec`) specify only the
+/// handful of fields a scenario cares about; anything omitted is filled in from
+/// `WorkloadConfig::default()`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 pub struct WorkloadConfig {
     pub num_writers: usize,
     pub num_readers: usize,
+    #[serde(serialize_with = "serialize_duration", deserialize_with = "deserialize_duration")]
     pub duration: Duration,
     pub writer_rate: f64,
     pub reader_rate: f64,
@@ -18,6 +23,239 @@ pub struct WorkloadConfig {
     pub key_pool_size: usize,
     pub key_overlap_ratio: f64,
     pub write_delete_ratio: f64,
+
+    /// Enables the per-key GCRA overflow limiter and hot-key rerouting in the `Writer` path.
+    pub hot_key_isolation: bool,
+    pub hot_key_per_second_limit: f64,
+    pub hot_key_burst_tolerance_ms: u64,
+    pub hot_key_ttl_secs: u64,
+    pub forced_overflow_keys: Vec<String>,
+
+    /// When set, transactions that exhaust retries or hit a hard error are captured as
+    /// JSONL dead letters at this path for later replay.
+    pub dlq_path: Option<String>,
+    /// Cap on `MetricsCollector`'s in-memory dead-letter buffer (drop-oldest once full),
+    /// drained via `WorkloadDriver::drain_dead_letters`/`replay_in_memory_dead_letters`.
+    /// Captured unconditionally regardless of `dlq_path`.
+    pub dlq_capacity: usize,
+
+    /// Number of puts/deletes staged per writer transaction before a single commit. `1`
+    /// (the default) preserves the original single-key-per-commit behavior.
+    pub ops_per_txn: usize,
+    pub batch_key_strategy: BatchKeyStrategy,
+
+    /// How long the supervisor waits for in-flight commits to finish after a shutdown
+    /// signal (Ctrl-C or test-driven) before abandoning unfinished worker tasks.
+    pub drain_timeout_secs: u64,
+
+    /// Shows a live indicatif progress bar with rolling TPS while the workload runs. Off
+    /// by default so headless CI doesn't write progress-bar escape codes into log capture.
+    pub progress: bool,
+
+    /// Shows a full-screen crossterm dashboard (see `crate::perf_test::tui`) with live write/
+    /// read TPS, rolling p50/p99 write latency, and cumulative precondition-failure rate. Off
+    /// by default so a sweep's parallel batches don't fight over the terminal; meant for a
+    /// single foreground run like `test_baseline` instead of `progress`'s one-line bar.
+    pub live_ui: bool,
+
+    /// Recomputes a checksum over every value a reader retrieves and compares it against
+    /// the digest recorded at write time, flagging mismatches as a distinct failure class.
+    pub checksum_verification: bool,
+    /// When checksum verification is on, also computes and compares a SHA-256 digest in
+    /// addition to the default CRC32C (mirrors S3's "additional checksum" opt-in).
+    pub checksum_include_sha256: bool,
+
+    /// Records every write/read as an `(invoke, complete)`-stamped entry in a shared
+    /// `OperationLog`, so the run can be checked for linearizability after the fact. Off by
+    /// default since the log grows unboundedly with run length.
+    pub history_tracking: bool,
+
+    /// Selects between a single whole-object PUT per writer transaction and a multipart
+    /// upload that splits the value across several concurrently-uploaded parts.
+    pub workload_mode: WorkloadMode,
+    /// When `workload_mode` is `Multipart`, interrupts the upload at this point instead of
+    /// completing it normally. `None` runs every multipart upload to completion.
+    pub multipart_fault: Option<crate::perf_test::multipart::MultipartFault>,
+
+    /// How readers and writers pick a key out of their `KeyPool` slice. `Uniform` (the
+    /// default) spreads access evenly; `Zipfian` concentrates most access on a hot subset
+    /// of keys to exercise the conditional-write paths that are most prone to contention.
+    pub key_distribution: KeyDistribution,
+
+    /// When non-zero, `WorkloadDriver::run` samples a windowed `IntervalSummary` this often
+    /// (via `MetricsCollector::sample_interval`) so throughput/latency can be plotted as a
+    /// time series. `0` (the default) disables interval sampling entirely.
+    pub report_interval_secs: u64,
+
+    /// When set, `WorkloadDriver::run` registers a `StatsdSink` pointed at this `host:port`
+    /// so counters/timings are forwarded live instead of only appearing in the final
+    /// `MetricsSummary`. `None` (the default) disables StatsD export.
+    pub statsd_addr: Option<String>,
+    /// When set, `WorkloadDriver::run` starts a `PrometheusSink` HTTP endpoint on this port
+    /// for scraping. `None` (the default) disables Prometheus export.
+    pub prometheus_port: Option<u16>,
+
+    /// Applies HdrHistogram's coordinated-omission correction to write/read latency
+    /// recording: when a request's latency exceeds the interval its rate should have
+    /// issued requests at, backfills the samples the stalled, un-issued requests would have
+    /// produced. Off by default so uncorrected numbers remain comparable to older runs.
+    pub correct_coordinated_omission: bool,
+
+    /// How writers/readers pace transactions against `writer_rate`/`reader_rate`.
+    /// `ClosedLoop` (the default) preserves the original fixed-rate-ticker behavior.
+    pub arrival_mode: ArrivalMode,
+
+    /// Pins writer/reader worker threads to specific host CPU cores for reproducible
+    /// scheduler placement, instead of leaving it to the default Tokio runtime. Writer and
+    /// reader `id`s share one index space, so a single rule set covers both. Empty (the
+    /// default) disables pinning entirely.
+    pub cpu_affinity: Vec<CpuAffinityRule>,
+
+    /// Wraps `WorkloadDriver::run`'s active phase (see `crate::perf_test::profiler`) with an
+    /// external sampling profiler or an in-process resource-usage time series. `None` (the
+    /// default) leaves `run()` unprofiled.
+    pub profiler: crate::perf_test::profiler::ProfilerKind,
+}
+
+/// Maps an inclusive range of worker indices to the host CPU core ids eligible for them. See
+/// `WorkloadConfig::cpu_affinity`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CpuAffinityRule {
+    /// Inclusive `(start, end)` worker index range this rule covers.
+    pub worker_range: (usize, usize),
+    /// Host CPU core ids a worker in `worker_range` may be pinned to.
+    pub cores: Vec<usize>,
+}
+
+/// Looks up the CPU core set assigned to `worker_id` by the first rule in `rules` whose
+/// `worker_range` contains it, or `None` if no rule matches (pinning is a no-op in that case).
+pub fn cores_for_worker(rules: &[CpuAffinityRule], worker_id: usize) -> Option<&[usize]> {
+    rules
+        .iter()
+        .find(|rule| worker_id >= rule.worker_range.0 && worker_id <= rule.worker_range.1)
+        .map(|rule| rule.cores.as_slice())
+}
+
+/// How `MockClient::run_loop`/`run_open_loop` paces transactions against the configured rate.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum ArrivalMode {
+    /// Each transaction starts only once the previous one completes. Under saturation the
+    /// achieved rate quietly falls below the configured one instead of queueing, so the
+    /// reported throughput describes a moving target rather than the offered load.
+    ClosedLoop,
+    /// Schedules transactions at Poisson-process arrival times (mean inter-arrival
+    /// `1 / rate`) and dispatches each one, as a separate task, the instant it comes due --
+    /// without waiting for earlier transactions to finish -- bounded to `max_in_flight`
+    /// concurrently running transactions. Saturation shows up as growing queueing delay
+    /// (see `MetricsCollector::record_queueing_delay`) instead of throttled throughput.
+    OpenLoop { max_in_flight: usize },
+}
+
+/// Access-pattern shape for key selection within a `KeyPool` slice.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum KeyDistribution {
+    /// Every key in the slice is equally likely to be picked.
+    Uniform,
+    /// Keys are ranked and picked following a Zipfian distribution with skew `theta`
+    /// (`0.0` degenerates to uniform; higher values concentrate access on rank 0).
+    Zipfian { theta: f64 },
+}
+
+/// Precomputed normalized cumulative weights for sampling ranks `0..n` under a Zipfian
+/// distribution, so each draw is a single binary search instead of recomputing the
+/// distribution every time.
+#[derive(Debug, Clone)]
+pub struct ZipfianSampler {
+    cumulative_weights: Vec<f64>,
+}
+
+impl ZipfianSampler {
+    pub fn new(n: usize, theta: f64) -> Self {
+        assert!(n > 0, "ZipfianSampler requires at least one rank");
+
+        let weights: Vec<f64> = (1..=n).map(|rank| 1.0 / (rank as f64).powf(theta)).collect();
+        let total: f64 = weights.iter().sum();
+
+        let mut cumulative_weights = Vec::with_capacity(n);
+        let mut running = 0.0;
+        for weight in weights {
+            running += weight / total;
+            cumulative_weights.push(running);
+        }
+        // Guard against floating-point drift leaving the last entry just under 1.0.
+        if let Some(last) = cumulative_weights.last_mut() {
+            *last = 1.0;
+        }
+
+        Self { cumulative_weights }
+    }
+
+    /// Draws a rank in `0..n` whose frequency follows the Zipfian CDF, via binary search
+    /// over a single uniform `[0, 1)` draw.
+    pub fn sample(&self, rng: &mut impl Rng) -> usize {
+        let draw: f64 = rng.gen();
+        match self
+            .cumulative_weights
+            .binary_search_by(|weight| weight.partial_cmp(&draw).unwrap())
+        {
+            Ok(idx) => idx,
+            Err(idx) => idx.min(self.cumulative_weights.len() - 1),
+        }
+    }
+}
+
+/// How a writer transaction turns `value_size` bytes into a committed object.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum WorkloadMode {
+    /// One whole-object PUT per write transaction (`value_size` bytes) -- the original
+    /// single-PUT write path.
+    WholeObject,
+    /// Initiates a multipart upload, uploads `num_parts` parts of `part_size` bytes each
+    /// concurrently, then completes with the assembled ETag.
+    Multipart { part_size: usize, num_parts: usize },
+    /// Transfer-ledger workload: `num_accounts` keys (see `banking_account_key`) each start
+    /// holding `initial_balance`, summing to a known total. Each write transaction reads two
+    /// accounts and commits a debit/credit between them through the manifest's existing
+    /// precondition/CAS path, so any write-skew anomaly surfaces as a balance-sum mismatch
+    /// (see `crate::perf_test::banking::check_banking_invariant`) instead of silently
+    /// corrupting the ledger.
+    Banking {
+        num_accounts: usize,
+        initial_balance: i64,
+        max_transfer_amount: i64,
+        /// When `false`, a transfer that would drive the source account negative is capped
+        /// at its current balance instead.
+        overdraft_allowed: bool,
+    },
+}
+
+/// The ledger key for banking account `idx` under `WorkloadMode::Banking`.
+pub fn banking_account_key(idx: usize) -> String {
+    format!("account_{:06}", idx)
+}
+
+fn serialize_duration<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+fn deserialize_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let secs: f64 = serde::Deserialize::deserialize(deserializer)?;
+    Ok(Duration::from_secs_f64(secs))
+}
+
+/// How a batched write transaction picks the keys for its staged ops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum BatchKeyStrategy {
+    /// Draw all keys in the batch from the writer's own key-pool slice.
+    SameWriterPool,
+    /// Draw each key independently from the full key pool, ignoring writer partitioning.
+    Random,
 }
 
 impl Default for WorkloadConfig {
@@ -34,6 +272,31 @@ impl Default for WorkloadConfig {
             key_pool_size: 100,
             key_overlap_ratio: 0.0,
             write_delete_ratio: 0.0,
+            hot_key_isolation: false,
+            hot_key_per_second_limit: 5.0,
+            hot_key_burst_tolerance_ms: 50,
+            hot_key_ttl_secs: 60,
+            forced_overflow_keys: Vec::new(),
+            dlq_path: None,
+            dlq_capacity: crate::perf_test::metrics::DEFAULT_DLQ_CAPACITY,
+            ops_per_txn: 1,
+            batch_key_strategy: BatchKeyStrategy::SameWriterPool,
+            drain_timeout_secs: 10,
+            progress: false,
+            live_ui: false,
+            checksum_verification: false,
+            checksum_include_sha256: false,
+            history_tracking: false,
+            workload_mode: WorkloadMode::WholeObject,
+            multipart_fault: None,
+            key_distribution: KeyDistribution::Uniform,
+            report_interval_secs: 0,
+            statsd_addr: None,
+            prometheus_port: None,
+            correct_coordinated_omission: false,
+            arrival_mode: ArrivalMode::ClosedLoop,
+            cpu_affinity: Vec::new(),
+            profiler: crate::perf_test::profiler::ProfilerKind::None,
         }
     }
 }
@@ -85,18 +348,42 @@ impl KeyRegistry {
 pub struct KeyPool {
     writer_key_sets: Vec<Vec<String>>,
     reader_keys: Vec<String>,
+    writer_samplers: Vec<Option<ZipfianSampler>>,
+    reader_sampler: Option<ZipfianSampler>,
 }
 
 impl KeyPool {
-    pub fn new(total_keys: usize, num_writers: usize, overlap_ratio: f64) -> Self {
+    pub fn new(
+        total_keys: usize,
+        num_writers: usize,
+        overlap_ratio: f64,
+        distribution: KeyDistribution,
+    ) -> Self {
+        Self::new_with_offset(0, total_keys, num_writers, overlap_ratio, distribution)
+    }
+
+    /// Like [`Self::new`], but every generated key starts at `offset` instead of `0`. Lets
+    /// `CombinationWorkload` hand each of its named legs a disjoint slice of one shared key
+    /// space, so one leg's precondition failures can't collide with or leak into another's.
+    pub fn new_with_offset(
+        offset: usize,
+        total_keys: usize,
+        num_writers: usize,
+        overlap_ratio: f64,
+        distribution: KeyDistribution,
+    ) -> Self {
         let all_keys: Vec<String> = (0..total_keys)
-            .map(|i| format!("key_{:06}", i))
+            .map(|i| format!("key_{:06}", offset + i))
             .collect();
 
+        let reader_sampler = Self::build_sampler(distribution, all_keys.len());
+
         if num_writers == 0 {
             return Self {
                 writer_key_sets: vec![],
                 reader_keys: all_keys,
+                writer_samplers: vec![],
+                reader_sampler,
             };
         }
 
@@ -117,9 +404,24 @@ impl KeyPool {
             writer_key_sets.push(writer_keys);
         }
 
+        let writer_samplers = writer_key_sets
+            .iter()
+            .map(|keys| Self::build_sampler(distribution, keys.len()))
+            .collect();
+
         Self {
             writer_key_sets,
             reader_keys: all_keys,
+            writer_samplers,
+            reader_sampler,
+        }
+    }
+
+    fn build_sampler(distribution: KeyDistribution, len: usize) -> Option<ZipfianSampler> {
+        match distribution {
+            KeyDistribution::Uniform => None,
+            KeyDistribution::Zipfian { theta } if len > 0 => Some(ZipfianSampler::new(len, theta)),
+            KeyDistribution::Zipfian { .. } => None,
         }
     }
 
@@ -130,6 +432,25 @@ impl KeyPool {
     pub fn reader_keys(&self) -> &[String] {
         &self.reader_keys
     }
+
+    /// Picks a key from `writer_id`'s slice, following the pool's configured
+    /// `KeyDistribution` (Zipfian-skewed if configured, otherwise uniform).
+    pub fn pick_writer_key(&self, writer_id: usize, rng: &mut impl Rng) -> &str {
+        let keys = self.writer_keys(writer_id);
+        match &self.writer_samplers[writer_id] {
+            Some(sampler) => &keys[sampler.sample(rng)],
+            None => keys.choose(rng).expect("writer key set is non-empty"),
+        }
+    }
+
+    /// Picks a key from the full reader key set, following the pool's configured
+    /// `KeyDistribution` (Zipfian-skewed if configured, otherwise uniform).
+    pub fn pick_reader_key(&self, rng: &mut impl Rng) -> &str {
+        match &self.reader_sampler {
+            Some(sampler) => &self.reader_keys[sampler.sample(rng)],
+            None => self.reader_keys.choose(rng).expect("reader key set is non-empty"),
+        }
+    }
 }
 
 pub fn create_test_prefix(test_name: &str) -> String {
@@ -199,6 +520,31 @@ pub fn generate_all_configs_v2() -> Vec<WorkloadConfig> {
                         key_pool_size: 100,
                         key_overlap_ratio: 0.0,
                         write_delete_ratio: 0.0,
+                        hot_key_isolation: false,
+                        hot_key_per_second_limit: 5.0,
+                        hot_key_burst_tolerance_ms: 50,
+                        hot_key_ttl_secs: 60,
+                        forced_overflow_keys: Vec::new(),
+                        dlq_path: None,
+                        dlq_capacity: crate::perf_test::metrics::DEFAULT_DLQ_CAPACITY,
+                        ops_per_txn: 1,
+                        batch_key_strategy: BatchKeyStrategy::SameWriterPool,
+                        drain_timeout_secs: 10,
+                        progress: false,
+                        live_ui: false,
+                        checksum_verification: false,
+                        checksum_include_sha256: false,
+                        history_tracking: false,
+                        workload_mode: WorkloadMode::WholeObject,
+                        multipart_fault: None,
+                        key_distribution: KeyDistribution::Uniform,
+                        report_interval_secs: 0,
+                        statsd_addr: None,
+                        prometheus_port: None,
+                        correct_coordinated_omission: false,
+                        arrival_mode: ArrivalMode::ClosedLoop,
+                        cpu_affinity: Vec::new(),
+                        profiler: crate::perf_test::profiler::ProfilerKind::None,
                     });
                 }
             }
@@ -219,6 +565,31 @@ pub fn generate_all_configs_v2() -> Vec<WorkloadConfig> {
             key_pool_size: 100,
             key_overlap_ratio: overlap_ratio,
             write_delete_ratio: 0.0,
+            hot_key_isolation: false,
+            hot_key_per_second_limit: 5.0,
+            hot_key_burst_tolerance_ms: 50,
+            hot_key_ttl_secs: 60,
+            forced_overflow_keys: Vec::new(),
+            dlq_path: None,
+            dlq_capacity: crate::perf_test::metrics::DEFAULT_DLQ_CAPACITY,
+            ops_per_txn: 1,
+            batch_key_strategy: BatchKeyStrategy::SameWriterPool,
+            drain_timeout_secs: 10,
+            progress: false,
+            live_ui: false,
+            checksum_verification: false,
+            checksum_include_sha256: false,
+            history_tracking: false,
+            workload_mode: WorkloadMode::WholeObject,
+            multipart_fault: None,
+            key_distribution: KeyDistribution::Uniform,
+            report_interval_secs: 0,
+            statsd_addr: None,
+            prometheus_port: None,
+            correct_coordinated_omission: false,
+            arrival_mode: ArrivalMode::ClosedLoop,
+            cpu_affinity: Vec::new(),
+            profiler: crate::perf_test::profiler::ProfilerKind::None,
         });
     }
 
@@ -250,6 +621,31 @@ pub fn generate_all_configs() -> Vec<WorkloadConfig> {
                             key_pool_size: 100,
                             key_overlap_ratio,
                             write_delete_ratio: 0.1,
+                            hot_key_isolation: false,
+                            hot_key_per_second_limit: 5.0,
+                            hot_key_burst_tolerance_ms: 50,
+                            hot_key_ttl_secs: 60,
+                            forced_overflow_keys: Vec::new(),
+                            dlq_path: None,
+                            dlq_capacity: crate::perf_test::metrics::DEFAULT_DLQ_CAPACITY,
+                            ops_per_txn: 1,
+                            batch_key_strategy: BatchKeyStrategy::SameWriterPool,
+                            drain_timeout_secs: 10,
+                            progress: false,
+                            live_ui: false,
+                            checksum_verification: false,
+                            checksum_include_sha256: false,
+                            history_tracking: false,
+                            workload_mode: WorkloadMode::WholeObject,
+                            multipart_fault: None,
+                            key_distribution: KeyDistribution::Uniform,
+                            report_interval_secs: 0,
+                            statsd_addr: None,
+                            prometheus_port: None,
+                            correct_coordinated_omission: false,
+                            arrival_mode: ArrivalMode::ClosedLoop,
+                            cpu_affinity: Vec::new(),
+                            profiler: crate::perf_test::profiler::ProfilerKind::None,
                         });
                     }
                 }
@@ -306,6 +702,31 @@ pub fn get_best_config_from_csv(csv_path: &str) -> Result<WorkloadConfig, Box<dy
                 key_pool_size,
                 key_overlap_ratio,
                 write_delete_ratio: 0.1,
+                hot_key_isolation: false,
+                hot_key_per_second_limit: 5.0,
+                hot_key_burst_tolerance_ms: 50,
+                hot_key_ttl_secs: 60,
+                forced_overflow_keys: Vec::new(),
+                dlq_path: None,
+                dlq_capacity: crate::perf_test::metrics::DEFAULT_DLQ_CAPACITY,
+                ops_per_txn: 1,
+                batch_key_strategy: BatchKeyStrategy::SameWriterPool,
+                drain_timeout_secs: 10,
+                progress: false,
+                live_ui: false,
+                checksum_verification: false,
+                checksum_include_sha256: false,
+                history_tracking: false,
+                workload_mode: WorkloadMode::WholeObject,
+                multipart_fault: None,
+                key_distribution: KeyDistribution::Uniform,
+                report_interval_secs: 0,
+                statsd_addr: None,
+                prometheus_port: None,
+                correct_coordinated_omission: false,
+                arrival_mode: ArrivalMode::ClosedLoop,
+                cpu_affinity: Vec::new(),
+                profiler: crate::perf_test::profiler::ProfilerKind::None,
             });
         }
     }
@@ -411,7 +832,7 @@ mod tests {
 
     #[test]
     fn test_key_pool_no_overlap() {
-        let pool = KeyPool::new(100, 4, 0.0);
+        let pool = KeyPool::new(100, 4, 0.0, KeyDistribution::Uniform);
         assert_eq!(pool.writer_key_sets.len(), 4);
         assert_eq!(pool.reader_keys.len(), 100);
 
@@ -422,7 +843,7 @@ mod tests {
 
     #[test]
     fn test_key_pool_with_overlap() {
-        let pool = KeyPool::new(100, 3, 0.2);
+        let pool = KeyPool::new(100, 3, 0.2, KeyDistribution::Uniform);
 
         assert_eq!(pool.writer_key_sets.len(), 3);
 
@@ -440,4 +861,31 @@ mod tests {
         assert!(overlap_count >= overlap.saturating_sub(1));
         assert!(overlap_count <= overlap + 1);
     }
+
+    #[test]
+    fn test_key_pool_offset_produces_disjoint_keys() {
+        let low = KeyPool::new_with_offset(0, 50, 1, 0.0, KeyDistribution::Uniform);
+        let high = KeyPool::new_with_offset(50, 50, 1, 0.0, KeyDistribution::Uniform);
+
+        assert_eq!(low.reader_keys().len(), 50);
+        assert_eq!(high.reader_keys().len(), 50);
+        assert!(low.reader_keys().iter().all(|k| !high.reader_keys().contains(k)));
+    }
+
+    #[test]
+    fn test_zipfian_sampler_concentrates_on_low_ranks() {
+        let sampler = ZipfianSampler::new(100, 1.5);
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let mut hits_rank_zero = 0;
+        for _ in 0..1000 {
+            if sampler.sample(&mut rng) == 0 {
+                hits_rank_zero += 1;
+            }
+        }
+
+        // With theta=1.5 over 100 ranks, rank 0 alone should dominate far beyond the 1%
+        // share a uniform distribution would give it.
+        assert!(hits_rank_zero > 300, "rank 0 only hit {} / 1000 draws", hits_rank_zero);
+    }
 }
@@ -19,6 +19,7 @@ pub fn export_results_csv(
     let mut wtr = Writer::from_writer(File::create(filename)?);
 
     wtr.write_record(&[
+        "workload",
         "config_label",
         "num_writers",
         "num_readers",
@@ -43,11 +44,22 @@ pub fn export_results_csv(
         "total_max_retries_exceeded",
         "retry_failure_rate",
         "retry_success_rate",
+        "total_hot_key_overflows",
+        "total_hot_key_reroutes",
+        "batch_ops_per_sec",
+        "batch_commits_per_sec",
+        "batch_precondition_failure_rate",
+        "total_checksum_mismatches",
+        "total_multipart_uploads_completed",
+        "total_multipart_uploads_aborted",
+        "total_multipart_uploads_dropped",
     ])?;
 
     for (config, summary) in results {
+        let config_label = create_config_label(config);
         wtr.write_record(&[
-            create_config_label(config),
+            String::new(),
+            config_label.clone(),
             config.num_writers.to_string(),
             config.num_readers.to_string(),
             config.writer_rate.to_string(),
@@ -71,7 +83,59 @@ pub fn export_results_csv(
             summary.total_max_retries_exceeded.to_string(),
             format!("{:.4}", summary.retry_failure_rate),
             format!("{:.4}", summary.retry_success_rate),
+            summary.total_hot_key_overflows.to_string(),
+            summary.total_hot_key_reroutes.to_string(),
+            format!("{:.2}", summary.batch_ops_per_sec),
+            format!("{:.2}", summary.batch_commits_per_sec),
+            format!("{:.4}", summary.batch_precondition_failure_rate),
+            summary.total_checksum_mismatches.to_string(),
+            summary.total_multipart_uploads_completed.to_string(),
+            summary.total_multipart_uploads_aborted.to_string(),
+            summary.total_multipart_uploads_dropped.to_string(),
         ])?;
+
+        // `CombinationWorkload` legs: one extra row per leg, sharing `config_label` with the
+        // aggregate row above it but carrying only the per-leg fields `WorkloadBreakdown`
+        // tracks -- everything else is blank rather than misleadingly repeating the aggregate
+        // run's config/totals under a single leg's name.
+        for leg in &summary.per_workload {
+            wtr.write_record(&[
+                leg.workload.clone(),
+                config_label.clone(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                format!("{:.4}", leg.precondition_failure_rate),
+                format!("{:.2}", leg.write_tps),
+                format!("{:.2}", leg.read_tps),
+                format!("{:.2}", leg.write_p50_ms),
+                String::new(),
+                format!("{:.2}", leg.write_p99_ms),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+            ])?;
+        }
     }
 
     wtr.flush()?;
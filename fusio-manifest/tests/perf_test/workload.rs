@@ -1,29 +1,81 @@
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Duration;
 
-use fusio::executor::tokio::TokioExecutor;
-use fusio_manifest::s3::S3Manifest;
+use indicatif::{ProgressBar, ProgressStyle};
 
 use crate::perf_test::{
+    backend::ManifestBackend,
+    banking::{self, BankingInvariantReport},
+    checksum::ChecksumRegistry,
     client::{ClientType, MockClient},
+    consistency::{self, ConsistencyReport},
+    dlq::{read_dlq_file, replay_dlq, DlqEntry, DlqReplayReport, DlqSink, JsonlDlqSink},
+    history::OperationLog,
+    linearizability::{self, LinearizabilityReport},
     metrics::{MetricsCollector, MetricsSummary},
-    utils::{KeyPool, WorkloadConfig},
+    metrics_sink::{MetricsSink, PrometheusSink, StatsdSink},
+    multipart::{LeakedUpload, MultipartRegistry},
+    overflow::GcraLimiter,
+    profiler::{build_profiler, ProfilerKind},
+    utils::{banking_account_key, create_config_label, ArrivalMode, KeyPool, WorkloadConfig, WorkloadMode},
 };
 
-pub struct WorkloadDriver {
+/// Default output path for a `WorkloadConfig.profiler` artifact, keyed by `create_config_label`
+/// the same way CSV/results-store rows are, so a sweep's profiles don't clobber each other.
+fn profiler_output_path(kind: ProfilerKind, config: &WorkloadConfig) -> String {
+    let label = create_config_label(config);
+    match kind {
+        ProfilerKind::None => String::new(),
+        ProfilerKind::Samply => format!("profile-{label}.json"),
+        ProfilerKind::SysMonitor => format!("profile-{label}-sysmonitor.csv"),
+    }
+}
+
+/// Spawns one client's transaction loop per `arrival_mode`: `ClosedLoop` runs the client
+/// directly as before, while `OpenLoop` hands it to `MockClient::run_open_loop`, which needs
+/// the client behind an `Arc` to dispatch transactions concurrently. Shared with
+/// `CombinationWorkload`, which spawns one loop per writer/reader per leg the same way
+/// `WorkloadDriver::run` does below.
+pub(crate) fn spawn_client_loop<B: ManifestBackend + 'static>(
+    client: MockClient<B>,
+    arrival_mode: ArrivalMode,
+    duration: Duration,
+    shutdown_rx: tokio::sync::watch::Receiver<bool>,
+) -> tokio::task::JoinHandle<()> {
+    match arrival_mode {
+        ArrivalMode::ClosedLoop => tokio::spawn(async move {
+            client.run_loop(duration, shutdown_rx).await;
+        }),
+        ArrivalMode::OpenLoop { max_in_flight } => {
+            let client = Arc::new(client);
+            tokio::spawn(async move {
+                client.run_open_loop(duration, max_in_flight, shutdown_rx).await;
+            })
+        }
+    }
+}
+
+pub struct WorkloadDriver<B: ManifestBackend + 'static> {
     config: WorkloadConfig,
-    manifest: Arc<S3Manifest<String, String, TokioExecutor>>,
+    manifest: Arc<B>,
     metrics: Arc<MetricsCollector>,
+    history: Option<Arc<OperationLog>>,
+    multipart_registry: Option<Arc<MultipartRegistry>>,
 }
 
-impl WorkloadDriver {
-    pub fn new(
-        config: WorkloadConfig,
-        manifest: Arc<S3Manifest<String, String, TokioExecutor>>,
-    ) -> Self {
+impl<B: ManifestBackend + 'static> WorkloadDriver<B> {
+    pub fn new(config: WorkloadConfig, manifest: Arc<B>) -> Self {
+        let history = config.history_tracking.then(|| Arc::new(OperationLog::new()));
+        let multipart_registry = matches!(config.workload_mode, WorkloadMode::Multipart { .. })
+            .then(|| Arc::new(MultipartRegistry::new()));
+        let metrics = Arc::new(MetricsCollector::with_dead_letter_capacity(config.dlq_capacity));
         Self {
             config,
             manifest,
-            metrics: Arc::new(MetricsCollector::new()),
+            metrics,
+            history,
+            multipart_registry,
         }
     }
 
@@ -31,6 +83,74 @@ impl WorkloadDriver {
         &self.metrics
     }
 
+    /// Multipart uploads (if `config.workload_mode` was `Multipart`) that were initiated but
+    /// never reached `CompleteMultipartUpload` or `AbortMultipartUpload` -- orphaned sessions
+    /// left behind by a dropped connection or an injected chaos fault.
+    pub fn leaked_multipart_uploads(&self) -> Vec<LeakedUpload> {
+        self.multipart_registry
+            .as_ref()
+            .map(|registry| registry.leaked_uploads())
+            .unwrap_or_default()
+    }
+
+    /// Checks the recorded operation history (if `config.history_tracking` was enabled) for
+    /// linearizability, one report per key. Returns `None` if history tracking was off.
+    pub fn check_linearizability(&self) -> Option<Vec<LinearizabilityReport>> {
+        let log = self.history.as_ref()?;
+        Some(linearizability::check_history(&log.snapshot()))
+    }
+
+    /// Checks the write/read records `MetricsCollector` always gathers during `run()` against
+    /// the manifest's snapshot semantics, flagging reads that returned a value inconsistent
+    /// with the committed write history for their key. Unlike `check_linearizability`, this
+    /// doesn't require `config.history_tracking`.
+    ///
+    /// Only valid for single-op `WholeObject` runs: `consistency::check_consistency` derives
+    /// each write's txn id from its global rank among `WriteRecord`s sorted by timestamp, which
+    /// is exact only when every commit writes exactly one key. Under `WorkloadMode::Banking` or
+    /// any `ops_per_txn > 1` run, one commit bumps the shared txn id by 1 while emitting several
+    /// `WriteRecord`s, so rank runs ahead of txn id and the checker would flag real reads as
+    /// spurious violations. Returns `None` outside that single-op whole-object case, and also
+    /// when `config.write_delete_ratio > 0`: a committed delete bumps the txn id without
+    /// emitting a `WriteRecord`, so rank falls behind txn id instead.
+    pub fn check_consistency(&self) -> Option<ConsistencyReport> {
+        let single_op_whole_object =
+            self.config.ops_per_txn == 1 && matches!(self.config.workload_mode, WorkloadMode::WholeObject);
+        if !single_op_whole_object || self.config.write_delete_ratio > 0.0 {
+            return None;
+        }
+        Some(consistency::check_consistency(&self.metrics.get_write_records(), &self.metrics.get_read_records()))
+    }
+
+    /// Sums the current balance of every `WorkloadMode::Banking` account and compares it
+    /// against the known starting total. Returns `None` if `config.workload_mode` isn't
+    /// `Banking`.
+    pub async fn check_banking_invariant(&self) -> Option<BankingInvariantReport> {
+        let WorkloadMode::Banking { num_accounts, initial_balance, .. } = self.config.workload_mode else {
+            return None;
+        };
+
+        let reader = self.manifest.session_read().await.expect("failed to open read session");
+        let entries = reader.scan();
+        let expected_total = num_accounts as i64 * initial_balance;
+
+        Some(banking::check_banking_invariant(&entries, num_accounts, expected_total))
+    }
+
+    /// Seeds each `WorkloadMode::Banking` account to `initial_balance` before any worker
+    /// starts. A no-op for every other `workload_mode`.
+    async fn seed_banking_accounts(&self) {
+        let WorkloadMode::Banking { num_accounts, initial_balance, .. } = self.config.workload_mode else {
+            return;
+        };
+
+        let mut session = self.manifest.session_write().await.expect("failed to open write session");
+        for idx in 0..num_accounts {
+            session.put(banking_account_key(idx), initial_balance.to_string());
+        }
+        session.commit().await.expect("failed to seed banking accounts");
+    }
+
     pub async fn run(&self) -> MetricsSummary {
         tracing::info!(
             num_writers = %self.config.num_writers,
@@ -42,12 +162,70 @@ impl WorkloadDriver {
             "starting workload"
         );
 
+        self.seed_banking_accounts().await;
+
         let key_pool = Arc::new(KeyPool::new(
             self.config.key_pool_size,
             self.config.num_writers,
             self.config.key_overlap_ratio,
+            self.config.key_distribution,
         ));
 
+        let hot_key_limiter = self.config.hot_key_isolation.then(|| {
+            let forced: HashSet<String> = self.config.forced_overflow_keys.iter().cloned().collect();
+            let limiter = Arc::new(GcraLimiter::new(
+                self.config.hot_key_per_second_limit,
+                Duration::from_millis(self.config.hot_key_burst_tolerance_ms),
+                Duration::from_secs(self.config.hot_key_ttl_secs),
+                forced,
+            ));
+            limiter.clone().spawn_cleaner(Duration::from_secs(10));
+            limiter
+        });
+
+        let dlq_sink: Option<Arc<dyn DlqSink>> = self.config.dlq_path.as_ref().map(|path| {
+            Arc::new(JsonlDlqSink::new(path).expect("failed to open DLQ file")) as Arc<dyn DlqSink>
+        });
+
+        let checksum_registry = self
+            .config
+            .checksum_verification
+            .then(|| Arc::new(ChecksumRegistry::new()));
+
+        if let Some(addr) = &self.config.statsd_addr {
+            let sink = Arc::new(StatsdSink::new(addr).expect("failed to open statsd socket"));
+            self.metrics.add_sink(sink);
+        }
+        let prometheus_sink = if let Some(port) = self.config.prometheus_port {
+            let sink = Arc::new(
+                PrometheusSink::start(port)
+                    .await
+                    .expect("failed to start prometheus sink"),
+            );
+            self.metrics.add_sink(sink.clone() as Arc<dyn MetricsSink>);
+            Some(sink)
+        } else {
+            None
+        };
+
+        let (shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
+        let ctrlc_shutdown_tx = shutdown_tx.clone();
+        let ctrlc_handle = tokio::spawn(async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                tracing::warn!("received Ctrl-C, draining in-flight commits and shutting down");
+                ctrlc_shutdown_tx.send_replace(true);
+            }
+        });
+
+        let mut profiler = build_profiler(
+            self.config.profiler,
+            profiler_output_path(self.config.profiler, &self.config),
+            self.metrics.clone(),
+        );
+        if let Some(profiler) = &mut profiler {
+            profiler.start();
+        }
+
         let mut handles = vec![];
 
         for writer_id in 0..self.config.num_writers {
@@ -60,11 +238,25 @@ impl WorkloadDriver {
                 Arc::new(self.config.clone()),
                 self.metrics.clone(),
             );
+            if let Some(limiter) = &hot_key_limiter {
+                client = client.with_hot_key_limiter(limiter.clone());
+            }
+            if let Some(sink) = &dlq_sink {
+                client = client.with_dlq_sink(sink.clone());
+            }
+            if let Some(registry) = &checksum_registry {
+                client = client.with_checksum_registry(registry.clone());
+            }
+            if let Some(log) = &self.history {
+                client = client.with_history_log(log.clone());
+            }
+            if let Some(registry) = &self.multipart_registry {
+                client = client.with_multipart_registry(registry.clone());
+            }
 
             let duration = self.config.duration;
-            let handle = tokio::spawn(async move {
-                client.run_loop(duration).await;
-            });
+            let shutdown_rx = shutdown_rx.clone();
+            let handle = spawn_client_loop(client, self.config.arrival_mode, duration, shutdown_rx);
             handles.push(handle);
         }
 
@@ -78,20 +270,156 @@ impl WorkloadDriver {
                 Arc::new(self.config.clone()),
                 self.metrics.clone(),
             );
+            if let Some(registry) = &checksum_registry {
+                client = client.with_checksum_registry(registry.clone());
+            }
+            if let Some(log) = &self.history {
+                client = client.with_history_log(log.clone());
+            }
 
             let duration = self.config.duration;
-            let handle = tokio::spawn(async move {
-                client.run_loop(duration).await;
-            });
+            let shutdown_rx = shutdown_rx.clone();
+            let handle = spawn_client_loop(client, self.config.arrival_mode, duration, shutdown_rx);
             handles.push(handle);
         }
 
-        for handle in handles {
-            handle.await.ok();
+        let live_ui_handle = self.config.live_ui.then(|| {
+            crate::perf_test::tui::spawn_dashboard(self.metrics.clone(), self.config.duration, shutdown_rx.clone())
+        });
+
+        let progress_handle = self.config.progress.then(|| {
+            let bar = ProgressBar::new(self.config.duration.as_secs());
+            bar.set_style(
+                ProgressStyle::with_template(
+                    "{elapsed_precise} [{bar:40.cyan/blue}] {pos}/{len}s  {msg}",
+                )
+                .unwrap()
+                .progress_chars("#>-"),
+            );
+            let metrics = self.metrics.clone();
+            let mut shutdown_rx = shutdown_rx.clone();
+            tokio::spawn(async move {
+                let mut ticker = tokio::time::interval(Duration::from_secs(1));
+                let mut elapsed_secs = 0;
+                loop {
+                    tokio::select! {
+                        _ = ticker.tick() => {}
+                        _ = shutdown_rx.changed() => {}
+                    }
+                    if *shutdown_rx.borrow() {
+                        break;
+                    }
+
+                    let snapshot = metrics.sample_rolling_window();
+                    bar.set_position(elapsed_secs);
+                    bar.set_message(format!(
+                        "write_tps={:.1} read_tps={:.1} precond_fail={:.1}% in_flight_retries={}",
+                        snapshot.write_tps,
+                        snapshot.read_tps,
+                        snapshot.precondition_failure_rate * 100.0,
+                        snapshot.in_flight_retries
+                    ));
+                    tracing::debug!(line_protocol = %snapshot.to_line_protocol(), "rolling window sample");
+
+                    elapsed_secs += 1;
+                    if elapsed_secs >= bar.length().unwrap_or(u64::MAX) {
+                        break;
+                    }
+                }
+                bar.finish_and_clear();
+            })
+        });
+
+        let interval_sampling_handle = (self.config.report_interval_secs > 0).then(|| {
+            let report_interval = Duration::from_secs(self.config.report_interval_secs);
+            let metrics = self.metrics.clone();
+            let mut shutdown_rx = shutdown_rx.clone();
+            tokio::spawn(async move {
+                let mut ticker = tokio::time::interval(report_interval);
+                ticker.tick().await; // the first tick fires immediately; skip it so the first real window has full length
+                loop {
+                    tokio::select! {
+                        _ = ticker.tick() => {}
+                        _ = shutdown_rx.changed() => {}
+                    }
+                    if *shutdown_rx.borrow() {
+                        break;
+                    }
+
+                    let window = metrics.sample_interval();
+                    tracing::info!(
+                        write_tps = %format!("{:.2}", window.write_tps),
+                        read_tps = %format!("{:.2}", window.read_tps),
+                        write_p99_ms = %format!("{:.2}", window.write_p99_ms),
+                        "interval metrics window"
+                    );
+                }
+            })
+        });
+
+        let drain_timeout = Duration::from_secs(self.config.drain_timeout_secs);
+        match tokio::time::timeout(drain_timeout, futures_util::future::join_all(handles)).await {
+            Ok(_) => tracing::info!("all workers joined cleanly"),
+            Err(_) => tracing::warn!(
+                drain_timeout_secs = %self.config.drain_timeout_secs,
+                "drain timeout elapsed before all workers joined, reporting partial results"
+            ),
+        }
+
+        ctrlc_handle.abort();
+        if let Some(handle) = live_ui_handle {
+            handle.abort();
+        }
+        if let Some(handle) = progress_handle {
+            handle.abort();
+        }
+        if let Some(handle) = interval_sampling_handle {
+            handle.abort();
+        }
+        if let Some(sink) = prometheus_sink {
+            sink.stop();
         }
 
         tracing::info!("workload completed");
 
-        self.metrics.summary()
+        let mut summary = self.metrics.summary();
+        if let Some(profiler) = profiler.take() {
+            summary.profile_artifact = Some(profiler.stop());
+        }
+        summary
+    }
+
+    /// Reads back this run's DLQ file (if `WorkloadConfig.dlq_path` was set) and replays
+    /// each captured transaction once against `self.manifest`, reporting how many would
+    /// now succeed.
+    pub async fn replay_dead_letters(&self) -> Result<DlqReplayReport, Box<dyn std::error::Error>> {
+        let path = self
+            .config
+            .dlq_path
+            .as_ref()
+            .ok_or("no dlq_path configured for this workload")?;
+
+        let entries = read_dlq_file(path)?;
+        tracing::info!(count = entries.len(), path, "replaying dead-lettered transactions");
+
+        Ok(replay_dlq(entries, &self.manifest).await)
+    }
+
+    /// Drains `MetricsCollector`'s bounded in-memory dead-letter buffer, independent of
+    /// whether `config.dlq_path` is set.
+    pub fn drain_dead_letters(&self) -> Vec<DlqEntry> {
+        self.metrics.drain_dead_letters()
+    }
+
+    /// Drains the in-memory dead-letter buffer and replays every entry once against
+    /// `self.manifest`, reporting how many would now succeed -- a high success rate
+    /// indicates the original failures were transient contention rather than real conflicts.
+    /// Unlike `replay_dead_letters`, this needs no `dlq_path` and reflects only the entries
+    /// still held in the buffer (older ones may already have been evicted, drop-oldest, if
+    /// the run exceeded `config.dlq_capacity`).
+    pub async fn replay_in_memory_dead_letters(&self) -> DlqReplayReport {
+        let entries = self.drain_dead_letters();
+        tracing::info!(count = entries.len(), "replaying in-memory dead-lettered transactions");
+        replay_dlq(entries, &self.manifest).await
     }
 }
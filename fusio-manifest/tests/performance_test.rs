@@ -1,51 +1,21 @@
 mod perf_test;
 
 use fusio::executor::tokio::TokioExecutor;
-use fusio_manifest::{context::ManifestContext, s3::{self, S3Manifest}};
+use fusio_manifest::s3::S3Manifest;
 use perf_test::{
-    utils::{create_test_prefix, create_config_label, create_sweep_prefix, create_test_prefix_in_sweep, generate_all_configs, load_aws_credentials, WorkloadConfig},
+    backend::S3Backend,
+    results_store::ResultsStore,
+    s3_setup::{
+        create_real_s3_manifest, create_real_s3_manifest_with_endpoint_override,
+        create_real_s3_manifest_with_prefix, s3_upstream_addr,
+    },
+    utils::{create_test_prefix, create_config_label, create_sweep_prefix, create_test_prefix_in_sweep, generate_all_configs, WorkloadConfig},
     visualization::{export_results_csv, export_single_result_csv},
     workload::WorkloadDriver,
 };
 use std::{env, sync::Arc, time::Instant};
 use tokio::task::JoinHandle;
 
-fn create_real_s3_manifest(
-    test_name: &str,
-) -> Result<S3Manifest<String, String, TokioExecutor>, Box<dyn std::error::Error>> {
-    let prefix = create_test_prefix(test_name);
-    create_real_s3_manifest_with_prefix(&prefix)
-}
-
-fn create_real_s3_manifest_with_prefix(
-    prefix: &str,
-) -> Result<S3Manifest<String, String, TokioExecutor>, Box<dyn std::error::Error>> {
-    let bucket = env::var("FUSIO_MANIFEST_BUCKET")
-        .unwrap_or_else(|_| "liguoso-tonbo-s3".to_string());
-
-    let creds = load_aws_credentials()?;
-    let endpoint = env::var("AWS_ENDPOINT_URL").ok();
-
-    let mut builder = s3::Builder::new(&bucket)
-        .prefix(prefix)
-        .region(creds.region)
-        .sign_payload(true)
-        .credential(fusio::impls::remotes::aws::credential::AwsCredential {
-            key_id: creds.access_key_id,
-            secret_key: creds.secret_access_key,
-            token: creds.session_token,
-        });
-
-    if let Some(ep) = endpoint {
-        builder = builder.endpoint(ep);
-    }
-
-    let config = builder.build();
-    let context = Arc::new(ManifestContext::new(TokioExecutor::default()));
-
-    Ok(config.with_context(context).into())
-}
-
 fn init_tracing() {
     use tracing_subscriber::{fmt, EnvFilter};
 
@@ -204,15 +174,16 @@ async fn verify_serializable_isolation_with_tracking(
 #[tokio::test]
 #[ignore]
 async fn test_baseline() {
-    init_tracing();
-
-    let config = WorkloadConfig::default();
+    let mut config = WorkloadConfig::default();
+    config.live_ui = true;
 
     let manifest = Arc::new(
         create_real_s3_manifest("baseline").expect("Failed to create S3 manifest")
     );
+    let backend = Arc::new(S3Backend::new(manifest.clone()));
 
-    let driver = WorkloadDriver::new(config.clone(), manifest.clone());
+    let driver = WorkloadDriver::new(config.clone(), backend);
+    let telemetry = perf_test::telemetry::init_telemetry(Some(driver.metrics()));
 
     println!("\n=== Running Baseline Test ===");
     let summary = driver.run().await;
@@ -232,6 +203,8 @@ async fn test_baseline() {
     verify_serializable_isolation(&manifest)
         .await
         .expect("Serialization verification failed");
+
+    telemetry.shutdown().await;
 }
 
 #[tokio::test]
@@ -253,8 +226,9 @@ async fn test_overlap_sweep() {
         let manifest = Arc::new(
             create_real_s3_manifest(&test_name).expect("Failed to create S3 manifest")
         );
+        let backend = Arc::new(S3Backend::new(manifest.clone()));
 
-        let driver = WorkloadDriver::new(config.clone(), manifest.clone());
+        let driver = WorkloadDriver::new(config.clone(), backend);
 
         println!("\n=== Running with overlap_ratio={} ===", overlap_ratio);
         let summary = driver.run().await;
@@ -332,8 +306,9 @@ async fn test_comprehensive_sweep() {
 
                 let manifest = Arc::new(create_real_s3_manifest_with_prefix(&test_prefix)
                     .map_err(|e| format!("Failed to create manifest for {}: {}", config_label, e))?);
+                let backend = Arc::new(S3Backend::new(manifest.clone()));
 
-                let driver = WorkloadDriver::new(config.clone(), manifest.clone());
+                let driver = WorkloadDriver::new(config.clone(), backend);
                 let summary = driver.run().await;
                 let metrics = driver.metrics().clone();
 
@@ -407,6 +382,26 @@ async fn test_comprehensive_sweep() {
     export_results_csv("comprehensive_sweep.csv", &all_results)
         .expect("Failed to export CSV");
 
+    let store = ResultsStore::open("perf_results.sqlite3").expect("Failed to open results store");
+    for (config, summary) in &all_results {
+        store
+            .record(&sweep_prefix, None, config, summary)
+            .expect("Failed to record result in results store");
+    }
+    store
+        .export_json(&sweep_prefix, "comprehensive_sweep.json")
+        .expect("Failed to export sweep JSON");
+
+    println!("\n=== Pareto Frontier (failure rate vs throughput) ===");
+    for row in store.pareto_frontier(&sweep_prefix).expect("Failed to compute Pareto frontier") {
+        println!(
+            "{}: {:.2}% failure rate, {:.2} TPS",
+            row.config_label,
+            row.precondition_failure_rate * 100.0,
+            row.write_tps + row.read_tps
+        );
+    }
+
     println!("\nGenerating visualizations...");
     let plot_result = std::process::Command::new("python3")
         .args(["plot_results.py", "comprehensive_sweep.csv"])
@@ -458,14 +453,17 @@ async fn test_comprehensive_sweep() {
 #[tokio::test]
 #[ignore]
 async fn test_chaos_sweep() {
-    use perf_test::{chaos::{create_chaos_scenarios, ChaosController}, metrics::MetricsSummary, utils::get_best_config_from_csv};
+    use perf_test::{chaos::{create_chaos_scenarios, ChaosController}, metrics::MetricsSummary};
     use std::time::Duration;
 
     init_tracing();
 
     println!("\n=== Loading Best Configuration from Phase 3 ===");
-    let mut best_config = get_best_config_from_csv("comprehensive_sweep.csv")
-        .expect("Failed to load best config from CSV. Run test_comprehensive_sweep first.");
+    let store = ResultsStore::open("perf_results.sqlite3").expect("Failed to open results store");
+    let mut best_config = store
+        .best_config_overall()
+        .expect("Failed to query results store")
+        .expect("No recorded results. Run test_comprehensive_sweep first.");
 
     best_config.duration = Duration::from_secs(300);
 
@@ -475,27 +473,41 @@ async fn test_chaos_sweep() {
 
     let scenarios = create_chaos_scenarios();
     let scenario_labels: Vec<String> = scenarios.iter().map(|s| s.label()).collect();
+    let num_scenarios = scenarios.len();
+
+    println!(
+        "\n=== Running Chaos Sweep ({} scenarios in parallel = ~5 minutes) ===",
+        num_scenarios
+    );
 
-    println!("\n=== Running Chaos Sweep (7 scenarios in parallel = ~5 minutes) ===");
+    let upstream_addr = s3_upstream_addr().expect("Failed to resolve S3 upstream address");
 
     let mut handles = Vec::new();
 
     for (idx, scenario) in scenarios.into_iter().enumerate() {
         let scenario_label = scenario_labels[idx].clone();
         let config = best_config.clone();
+        let upstream_addr = upstream_addr.clone();
 
         let handle = tokio::spawn(async move {
-            println!("[{}/7] Starting scenario: {}", idx + 1, scenario_label);
+            println!("[{}/{}] Starting scenario: {}", idx + 1, num_scenarios, scenario_label);
+
+            let mut chaos_controller = ChaosController::new(scenario.clone());
+
+            // Proxy-backed scenarios (PacketLoss/BandwidthCap/ServerErrors) need the proxy
+            // running before the manifest is built, so its local URL can override the endpoint.
+            let proxy_url = chaos_controller.start_proxy(&upstream_addr).await;
+            chaos_controller.start();
 
             let test_name = format!("chaos-{}", scenario_label);
+            let prefix = create_test_prefix(&test_name);
             let manifest = Arc::new(
-                create_real_s3_manifest(&test_name).expect("Failed to create S3 manifest")
+                create_real_s3_manifest_with_endpoint_override(&prefix, proxy_url)
+                    .expect("Failed to create S3 manifest"),
             );
 
-            let mut chaos_controller = ChaosController::new(scenario.clone());
-            chaos_controller.start();
-
-            let driver = WorkloadDriver::new(config.clone(), manifest.clone());
+            let backend = Arc::new(S3Backend::new(manifest.clone()));
+            let driver = WorkloadDriver::new(config.clone(), backend);
             let summary = driver.run().await;
             let metrics = driver.metrics().clone();
 
@@ -538,6 +550,13 @@ async fn test_chaos_sweep() {
     export_results_csv("chaos_sweep.csv", &results)
         .expect("Failed to export chaos results");
 
+    let chaos_sweep_prefix = format!("chaos-{}", create_sweep_prefix());
+    for (idx, (config, summary)) in results.iter().enumerate() {
+        store
+            .record(&chaos_sweep_prefix, Some(&scenario_labels[idx]), config, summary)
+            .expect("Failed to record chaos result in results store");
+    }
+
     println!("\n=== Chaos Sweep Summary ===");
     for (idx, label) in scenario_labels.iter().enumerate() {
         let summary = &results[idx].1;